@@ -1,5 +0,0 @@
-#[derive(Clone, Copy, PartialEq, Eq)]
-pub enum Color {
-    Off,
-    On,
-}
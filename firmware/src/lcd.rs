@@ -1,5 +1,3 @@
-#![allow(dead_code)]
-
 use atmega_hal::{clock::MHz16, delay::Delay, port::Dynamic};
 use avr_hal_generic::port::{mode::Output, Pin, PinOps};
 use avr_hal_generic::prelude::*;
@@ -7,24 +5,34 @@ use avr_hal_generic::prelude::*;
 mod buffered;
 mod builder;
 mod commands;
+mod config;
+mod transport;
 
 pub use self::buffered::*;
 pub use self::commands::*;
+pub use self::config::*;
+pub use self::transport::*;
 
 /// Provides a basic interface for the LCD-10168 chip. See the
 /// [full specification](https://www.sparkfun.com/datasheets/LCD/Monochrome/Nokia5110.pdf)
 /// of the chip for more details.
-pub struct Lcd10168<RST = Dynamic, SCE = Dynamic, DC = Dynamic, DIN = Dynamic, CLK = Dynamic> {
+///
+/// The bytes are sent to the chip through a [`Transport`], while the reset,
+/// chip enable and data/command pins are always driven directly.
+pub struct Lcd10168<RST = Dynamic, SCE = Dynamic, DC = Dynamic, T = BitBang> {
     rst: Pin<Output, RST>,
     sce: Pin<Output, SCE>,
     dc: Pin<Output, DC>,
-    din: Pin<Output, DIN>,
-    clk: Pin<Output, CLK>,
+    transport: T,
 
     delay: Delay<MHz16>,
+
+    // the modes sent along with every instruction set switch
+    chip_mode: ChipMode,
+    addressing_mode: AddressingMode,
 }
 
-impl Lcd10168<Dynamic, Dynamic, Dynamic, Dynamic, Dynamic> {
+impl Lcd10168<Dynamic, Dynamic, Dynamic, BitBang> {
     pub const COLUMNS: usize = 84;
     pub const ROWS: usize = 6;
 }
@@ -35,10 +43,9 @@ macro_rules! command {
     };
 }
 
-impl<RST: PinOps, SCE: PinOps, DC: PinOps, DIN: PinOps, CLK: PinOps>
-    Lcd10168<RST, SCE, DC, DIN, CLK>
-{
-    /// Executes a reset sequence of the chip.
+impl<RST: PinOps, SCE: PinOps, DC: PinOps, T: Transport> Lcd10168<RST, SCE, DC, T> {
+    /// Executes a reset sequence of the chip, which puts it in power down mode
+    /// with horizontal addressing and the basic instruction set.
     pub fn reset(&mut self) {
         self.rst.set_high();
         self.sce.set_high();
@@ -47,90 +54,62 @@ impl<RST: PinOps, SCE: PinOps, DC: PinOps, DIN: PinOps, CLK: PinOps>
         self.delay.delay_ms(70u16);
         self.rst.set_high();
         self.sce.set_low();
+
+        self.chip_mode = ChipMode::PowerDown;
+        self.addressing_mode = AddressingMode::Horizontal;
+    }
+
+    /// Sends a command for controlling the chip mode (active/power down) and the addressing
+    /// mode (horizontal/vertical) simultaneously.
+    ///
+    /// The chip is always left with the [`Basic`](InstructionSet::Basic) instruction set.
+    /// The commands of the [`Extended`](InstructionSet::Extended) instruction set are only
+    /// available through [`extended`](Self::extended).
+    pub fn function_set(&mut self, chip_mode: ChipMode, addressing_mode: AddressingMode) {
+        self.chip_mode = chip_mode;
+        self.addressing_mode = addressing_mode;
+        self.write_function_set(InstructionSet::Basic);
     }
 
-    /// Sends a command for controlling the chip mode (active/power down), the addressing
-    /// mode (horizontal/vertical), and the instruction set (basic/extended) simultaneously.
-    pub fn function_set(
+    /// Switches the chip to the [`Extended`](InstructionSet::Extended) instruction set for
+    /// the duration of `f`, and back to the [`Basic`](InstructionSet::Basic) one afterwards.
+    pub fn extended<R>(
         &mut self,
-        chip_mode: ChipMode,
-        addressing_mode: AddressingMode,
-        instruction_set: InstructionSet,
-    ) {
-        self.write_command(command!(5 => chip_mode, addressing_mode, instruction_set));
+        f: impl FnOnce(&mut ExtendedLcd10168<'_, RST, SCE, DC, T>) -> R,
+    ) -> R {
+        self.write_function_set(InstructionSet::Extended);
+        let result = f(&mut ExtendedLcd10168 { lcd: self });
+        self.write_function_set(InstructionSet::Basic);
+
+        result
     }
 
     /// Sends a command for setting the X cursor of the display, or rather the X address of
     /// the display RAM.
-    ///
-    /// # Safety
-    ///
-    /// The instruction set of the LCD must be set to [`Basic`](InstructionSet::Basic).
-    /// `x` must be a value less than 84.
-    pub unsafe fn set_x_cursor(&mut self, x: u8) {
-        debug_assert!(x < 84);
-        self.write_command(command!(7 => x));
+    pub fn set_x_cursor(&mut self, x: XAddress) {
+        self.write_command(command!(7 => x.get()));
     }
 
     /// Sends a command for setting the Y cursor of the display, or rather the Y address of
     /// the display RAM.
-    ///
-    /// # Safety
-    ///
-    /// The instruction set of the LCD must be set to [`Basic`](InstructionSet::Basic).
-    /// `y` must be a value less than 6.
-    pub unsafe fn set_y_cursor(&mut self, y: u8) {
-        debug_assert!(y < 6);
-        self.write_command(command!(6 => y));
+    pub fn set_y_cursor(&mut self, y: YAddress) {
+        self.write_command(command!(6 => y.get()));
     }
 
     /// Sends a command for setting the mode of the display.
-    ///
-    /// # Safety
-    ///
-    /// The instruction set of the LCD must be set to [`Basic`](InstructionSet::Basic).
-    pub unsafe fn set_display_mode(&mut self, display_mode: DisplayMode) {
+    pub fn set_display_mode(&mut self, display_mode: DisplayMode) {
         self.write_command(command!(3 => display_mode));
     }
 
-    /// Sends a command for setting the bias system voltage coefficient.
-    ///
-    /// # Safety
-    ///
-    /// The instruction set of the LCD must be set to [`Extended`](InstructionSet::Extended).
-    /// `coeff` must be a value less than 8.
-    pub unsafe fn set_bias_voltage_coefficient(&mut self, coeff: u8) {
-        debug_assert!(coeff < 8);
-        self.write_command(command!(4 => coeff));
-    }
-
-    /// Sends a command for setting the temperature coefficient.
-    ///
-    /// # Safety
-    ///
-    /// The instruction set of the LCD must be set to [`Extended`](InstructionSet::Extended).
-    /// `coeff` must be a value less than 4.
-    pub unsafe fn set_temperature_coefficient(&mut self, coeff: u8) {
-        debug_assert!(coeff < 4);
-        self.write_command(command!(2 => coeff));
-    }
-
-    /// Sends a command for setting the operation voltage.
-    ///
-    /// # Safety
-    ///
-    /// The instruction set of the LCD must be set to [`Extended`](InstructionSet::Extended).
-    /// `voltage` must be a value less than 128.
-    pub unsafe fn set_operation_voltage(&mut self, voltage: u8) {
-        assert!(voltage < 128);
-        self.write_command(command!(7 => voltage));
-    }
-
     /// Writes data to the display RAM.
     pub fn write_data(&mut self, data: u8) {
         self.write(data, true);
     }
 
+    fn write_function_set(&mut self, instruction_set: InstructionSet) {
+        self.write_command(command!(5 => self.chip_mode, self.addressing_mode, instruction_set));
+    }
+
     fn write_command(&mut self, data: u8) {
         self.write(data, false);
     }
@@ -144,34 +123,45 @@ impl<RST: PinOps, SCE: PinOps, DC: PinOps, DIN: PinOps, CLK: PinOps>
             self.dc.set_low();
         }
 
-        for i in 0..8 {
-            if ((value >> (7 - i)) & 1) != 0 {
-                self.din.set_high();
-            } else {
-                self.din.set_low();
-            }
-
-            self.clk.set_high();
-            self.clk.set_low();
-        }
+        self.transport.write(value);
 
         self.sce.set_high();
     }
 }
 
-impl<RST: PinOps, SCE: PinOps, DC: PinOps, DIN: PinOps, CLK: PinOps>
-    Lcd10168<RST, SCE, DC, DIN, CLK>
-{
-    pub fn downgrade(
-        self,
-    ) -> Lcd10168<RST::Dynamic, SCE::Dynamic, DC::Dynamic, DIN::Dynamic, CLK::Dynamic> {
+/// Provides the commands of the [`Extended`](InstructionSet::Extended)
+/// instruction set. See [`Lcd10168::extended`] for how to acquire one.
+pub struct ExtendedLcd10168<'a, RST, SCE, DC, T> {
+    lcd: &'a mut Lcd10168<RST, SCE, DC, T>,
+}
+
+impl<RST: PinOps, SCE: PinOps, DC: PinOps, T: Transport> ExtendedLcd10168<'_, RST, SCE, DC, T> {
+    /// Sends a command for setting the bias system voltage coefficient.
+    pub fn set_bias_voltage_coefficient(&mut self, coeff: BiasCoefficient) {
+        self.lcd.write_command(command!(4 => coeff.get()));
+    }
+
+    /// Sends a command for setting the temperature coefficient.
+    pub fn set_temperature_coefficient(&mut self, coeff: TemperatureCoefficient) {
+        self.lcd.write_command(command!(2 => coeff.get()));
+    }
+
+    /// Sends a command for setting the operation voltage.
+    pub fn set_operation_voltage(&mut self, voltage: OperationVoltage) {
+        self.lcd.write_command(command!(7 => voltage.get()));
+    }
+}
+
+impl<RST: PinOps, SCE: PinOps, DC: PinOps, T: Transport> Lcd10168<RST, SCE, DC, T> {
+    pub fn downgrade(self) -> Lcd10168<RST::Dynamic, SCE::Dynamic, DC::Dynamic, T::Dynamic> {
         Lcd10168 {
             rst: self.rst.downgrade(),
             sce: self.sce.downgrade(),
             dc: self.dc.downgrade(),
-            din: self.din.downgrade(),
-            clk: self.clk.downgrade(),
+            transport: self.transport.downgrade(),
             delay: self.delay,
+            chip_mode: self.chip_mode,
+            addressing_mode: self.addressing_mode,
         }
     }
 }
@@ -1,125 +1,258 @@
-use super::{AddressingMode, ChipMode, DisplayMode, InstructionSet, Lcd10168};
-use crate::canvas::{Canvas, Color};
+use super::{
+    AddressingMode, BiasCoefficient, BitBang, ChipMode, DisplayMode, Lcd10168, LcdConfig,
+    OperationVoltage, Transport, XAddress, YAddress,
+};
 use atmega_hal::port::Dynamic;
 use avr_hal_generic::port::PinOps;
+use core::ops::RangeInclusive;
+use hilton_graphics::canvas::{Canvas, ChunkSpan, Color, ReadableCanvas, Vec2};
 
 mod chunk;
+mod console;
+mod dirty;
+#[cfg(feature = "embedded-graphics")]
+mod draw_target;
 
 pub use self::chunk::*;
+pub use self::console::*;
 
-pub struct BufLcd10168<RST = Dynamic, SCE = Dynamic, DC = Dynamic, DIN = Dynamic, CLK = Dynamic> {
-    lcd: Lcd10168<RST, SCE, DC, DIN, CLK>,
+use self::dirty::DirtyRows;
+
+pub struct BufLcd10168<RST = Dynamic, SCE = Dynamic, DC = Dynamic, T = BitBang> {
+    lcd: Lcd10168<RST, SCE, DC, T>,
     buffer: [Chunk; Lcd10168::COLUMNS * Lcd10168::ROWS],
+    dirty: DirtyRows,
+    config: LcdConfig,
+    asleep: bool,
 }
 
-impl<RST: PinOps, SCE: PinOps, DC: PinOps, DIN: PinOps, CLK: PinOps>
-    Lcd10168<RST, SCE, DC, DIN, CLK>
-{
-    pub fn into_buffered(self) -> UninitBufLcd10168<RST, SCE, DC, DIN, CLK> {
+impl<RST: PinOps, SCE: PinOps, DC: PinOps, T: Transport> Lcd10168<RST, SCE, DC, T> {
+    pub fn into_buffered(self) -> UninitBufLcd10168<RST, SCE, DC, T> {
         UninitBufLcd10168 { lcd: self }
     }
 }
 
-pub struct UninitBufLcd10168<RST, SCE, DC, DIN, CLK> {
-    lcd: Lcd10168<RST, SCE, DC, DIN, CLK>,
+pub struct UninitBufLcd10168<RST, SCE, DC, T> {
+    lcd: Lcd10168<RST, SCE, DC, T>,
 }
 
-impl<RST: PinOps, SCE: PinOps, DC: PinOps, DIN: PinOps, CLK: PinOps>
-    UninitBufLcd10168<RST, SCE, DC, DIN, CLK>
-{
-    pub fn init(self) -> BufLcd10168<RST, SCE, DC, DIN, CLK> {
+impl<RST: PinOps, SCE: PinOps, DC: PinOps, T: Transport> UninitBufLcd10168<RST, SCE, DC, T> {
+    pub fn init(self, config: LcdConfig) -> BufLcd10168<RST, SCE, DC, T> {
         let Self { mut lcd } = self;
 
         lcd.reset();
-        lcd.function_set(
-            ChipMode::Active,
-            AddressingMode::Horizontal,
-            InstructionSet::Extended,
-        );
-        // SAFETY: the instruction set has been set to extended on the line above
-        unsafe {
-            lcd.set_bias_voltage_coefficient(0);
-            lcd.set_temperature_coefficient(0);
-            lcd.set_operation_voltage(50);
-        }
-        lcd.function_set(
-            ChipMode::Active,
-            AddressingMode::Horizontal,
-            InstructionSet::Basic,
-        );
-        // SAFETY: the instruction set has been set to basic on the line above
-        unsafe { lcd.set_display_mode(DisplayMode::Normal) };
+        lcd.function_set(ChipMode::Active, AddressingMode::Horizontal);
+        lcd.configure(config);
+        lcd.set_display_mode(DisplayMode::Normal);
 
         BufLcd10168 {
             lcd,
             buffer: [Default::default(); Lcd10168::COLUMNS * Lcd10168::ROWS],
+            // the contents of the display RAM are undefined after a reset
+            dirty: DirtyRows::all(),
+            config,
+            asleep: false,
         }
     }
 }
 
-impl<RST, SCE, DC, DIN, CLK> BufLcd10168<RST, SCE, DC, DIN, CLK> {
+impl<RST, SCE, DC, T> BufLcd10168<RST, SCE, DC, T> {
+    const SIZE: Vec2<usize> = Vec2::new(Lcd10168::COLUMNS, Lcd10168::ROWS * 8);
+
+    /// Returns the chunk containing the pixel at `(x, y)`. The chunk gets
+    /// marked as modified, whether it actually gets modified or not.
     pub fn chunk_at(&mut self, x: usize, y: usize) -> &mut Chunk {
         self.chunk_at_raw(x, y / 8)
     }
 
+    /// Returns the chunk in the `column` of the `row` of chunks. The chunk gets
+    /// marked as modified, whether it actually gets modified or not.
     pub fn chunk_at_raw(&mut self, column: usize, row: usize) -> &mut Chunk {
+        self.dirty.mark(row, column..=column);
         &mut self.buffer[row * Lcd10168::COLUMNS + column]
     }
 
     pub fn clear(&mut self) {
         self.buffer.fill(Default::default());
+        self.invalidate();
+    }
+
+    /// Marks the whole buffer as modified, so that all of it gets sent to the
+    /// display on the next [`display_frame`](Self::display_frame).
+    pub fn invalidate(&mut self) {
+        self.dirty = DirtyRows::all();
+    }
+
+    /// Blits the mask of the span onto its chunks, marking only the chunks
+    /// which have changed as modified.
+    fn blit_chunk_span(&mut self, span: ChunkSpan, color: Color) {
+        let offset = span.row * Lcd10168::COLUMNS;
+        let chunks = &mut self.buffer[offset + span.columns.start..offset + span.columns.end];
+
+        for (column, chunk) in span.columns.zip(chunks) {
+            if blit_mask(chunk, span.mask.into(), color) {
+                self.dirty.mark(span.row, column..=column);
+            }
+        }
     }
 }
 
-impl<RST: PinOps, SCE: PinOps, DC: PinOps, DIN: PinOps, CLK: PinOps> Canvas
-    for BufLcd10168<RST, SCE, DC, DIN, CLK>
-{
+impl<RST: PinOps, SCE: PinOps, DC: PinOps, T: Transport> Canvas for BufLcd10168<RST, SCE, DC, T> {
     fn blit_pixel(&mut self, x: isize, y: isize, color: Color) {
-        let x = match usize::try_from(x) {
-            Ok(x) => x,
-            Err(_) => return,
-        };
-        let y = match usize::try_from(y) {
-            Ok(y) => y,
-            Err(_) => return,
-        };
-
-        let chunk = self.chunk_at(x, y);
-        let mask = Chunk::bit(y % 8);
-
-        match color {
-            Color::On => *chunk |= mask,
-            Color::Off => *chunk &= !mask,
+        self.blit_span(x..=x, y, color);
+    }
+
+    /// Blits the span with a single mask applied to every chunk it covers.
+    fn blit_span(&mut self, xs: RangeInclusive<isize>, y: isize, color: Color) {
+        if let Some(span) = ChunkSpan::of_span(xs, y, Self::SIZE) {
+            self.blit_chunk_span(span, color);
+        }
+    }
+
+    /// Blits the chunk with at most two masks, one for each row of chunks it
+    /// overlaps.
+    fn blit_chunk(&mut self, x: isize, y: isize, chunk: u8, color: Color) {
+        for span in ChunkSpan::of_chunk(x, y, chunk, Self::SIZE) {
+            self.blit_chunk_span(span, color);
+        }
+    }
+
+    /// Fills the rectangle with a single mask for every row of chunks it
+    /// overlaps.
+    fn fill_rect(&mut self, position: Vec2<isize>, size: Vec2<isize>, color: Color) {
+        for span in ChunkSpan::of_rect(position, size, Self::SIZE) {
+            self.blit_chunk_span(span, color);
         }
     }
 }
 
-impl<RST: PinOps, SCE: PinOps, DC: PinOps, DIN: PinOps, CLK: PinOps>
-    BufLcd10168<RST, SCE, DC, DIN, CLK>
+impl<RST: PinOps, SCE: PinOps, DC: PinOps, T: Transport> ReadableCanvas
+    for BufLcd10168<RST, SCE, DC, T>
 {
+    fn get_pixel(&self, x: isize, y: isize) -> Option<Color> {
+        let x = usize::try_from(x).ok().filter(|&x| x < Lcd10168::COLUMNS)?;
+        let y = usize::try_from(y)
+            .ok()
+            .filter(|&y| y < Lcd10168::ROWS * 8)?;
+        let chunk = self.buffer[y / 8 * Lcd10168::COLUMNS + x];
+
+        match u8::from(chunk & Chunk::bit(y % 8)) {
+            0 => Some(Color::Off),
+            _ => Some(Color::On),
+        }
+    }
+}
+
+/// Blits the mask onto the chunk, returning whether the chunk has changed.
+fn blit_mask(chunk: &mut Chunk, mask: Chunk, color: Color) -> bool {
+    let previous = u8::from(*chunk);
+
+    match color {
+        Color::On => *chunk |= mask,
+        Color::Off => *chunk &= !mask,
+        Color::Toggle => *chunk ^= mask,
+    }
+
+    u8::from(*chunk) != previous
+}
+
+impl<RST: PinOps, SCE: PinOps, DC: PinOps, T: Transport> BufLcd10168<RST, SCE, DC, T> {
+    /// Sends the chunks modified since the last frame to the display, moving
+    /// the cursor to the first modified chunk of every row of chunks and
+    /// sending everything up to the last modified chunk of that row.
+    ///
+    /// Does nothing while the display is asleep.
     pub fn display_frame(&mut self) {
-        // SAFETY: we couldn't have acquired a `BufLcd10168` without calling
-        // `UninitBufLcd10168::init` which sets the instruction set to basic
-        unsafe {
-            self.lcd.set_x_cursor(0);
-            self.lcd.set_y_cursor(0);
+        if self.asleep {
+            return;
         }
 
-        for chunk in self.buffer {
-            self.lcd.write_data(chunk.into());
+        for row in 0..Lcd10168::ROWS {
+            let columns = match self.dirty.take(row) {
+                Some(columns) => columns,
+                None => continue,
+            };
+
+            self.lcd
+                .set_x_cursor(XAddress::saturating(*columns.start()));
+            self.lcd.set_y_cursor(YAddress::saturating(row));
+
+            let offset = row * Lcd10168::COLUMNS;
+
+            for &chunk in &self.buffer[offset + columns.start()..=offset + columns.end()] {
+                self.lcd.write_data(chunk.into());
+            }
         }
     }
 }
 
-impl<RST: PinOps, SCE: PinOps, DC: PinOps, DIN: PinOps, CLK: PinOps>
-    BufLcd10168<RST, SCE, DC, DIN, CLK>
-{
-    pub fn downgrade(
-        self,
-    ) -> BufLcd10168<RST::Dynamic, SCE::Dynamic, DC::Dynamic, DIN::Dynamic, CLK::Dynamic> {
+impl<RST: PinOps, SCE: PinOps, DC: PinOps, T: Transport> BufLcd10168<RST, SCE, DC, T> {
+    /// Returns the settings the display is currently driven with.
+    #[inline]
+    pub fn config(&self) -> LcdConfig {
+        self.config
+    }
+
+    /// Changes the operation voltage of the display right away, without
+    /// resetting it.
+    pub fn set_contrast(&mut self, contrast: OperationVoltage) {
+        self.config.contrast = contrast;
+        self.lcd.extended(|lcd| lcd.set_operation_voltage(contrast));
+    }
+
+    /// Changes the bias system voltage coefficient of the display right away,
+    /// without resetting it.
+    pub fn set_bias(&mut self, bias: BiasCoefficient) {
+        self.config.bias = bias;
+        self.lcd
+            .extended(|lcd| lcd.set_bias_voltage_coefficient(bias));
+    }
+}
+
+impl<RST: PinOps, SCE: PinOps, DC: PinOps, T: Transport> BufLcd10168<RST, SCE, DC, T> {
+    /// Puts the display into power down mode, in which it shows nothing and
+    /// draws the least current.
+    ///
+    /// The buffer stays intact and can still be drawn onto. Nothing gets
+    /// displayed until [`wake`](Self::wake) is called.
+    pub fn sleep(&mut self) {
+        // the display RAM has to be filled with zeros for the current draw to
+        // drop as low as it can, so it gets blanked first to hide the clearing
+        self.lcd.set_display_mode(DisplayMode::Blank);
+        self.lcd.set_x_cursor(XAddress::MIN);
+        self.lcd.set_y_cursor(YAddress::MIN);
+
+        for _ in 0..self.buffer.len() {
+            self.lcd.write_data(0);
+        }
+
+        self.lcd
+            .function_set(ChipMode::PowerDown, AddressingMode::Horizontal);
+        self.asleep = true;
+    }
+
+    /// Brings the display back from power down mode, restoring its settings
+    /// and sending the whole buffer to it.
+    pub fn wake(&mut self) {
+        self.lcd
+            .function_set(ChipMode::Active, AddressingMode::Horizontal);
+        self.lcd.configure(self.config);
+        self.lcd.set_display_mode(DisplayMode::Normal);
+
+        self.asleep = false;
+        self.invalidate();
+        self.display_frame();
+    }
+}
+
+impl<RST: PinOps, SCE: PinOps, DC: PinOps, T: Transport> BufLcd10168<RST, SCE, DC, T> {
+    pub fn downgrade(self) -> BufLcd10168<RST::Dynamic, SCE::Dynamic, DC::Dynamic, T::Dynamic> {
         BufLcd10168 {
             lcd: self.lcd.downgrade(),
             buffer: self.buffer,
+            dirty: self.dirty,
+            config: self.config,
+            asleep: self.asleep,
         }
     }
 }
@@ -0,0 +1,141 @@
+use super::{BufLcd10168, Chunk};
+use crate::lcd::{Lcd10168, Transport};
+use avr_hal_generic::port::PinOps;
+use core::fmt;
+use hilton_graphics::canvas::{Color, Font, Vec2};
+
+/// A scrolling text terminal drawing onto a [`BufLcd10168`], using the
+/// [regular](Font::regular) font.
+///
+/// The console takes over the display, which can still be drawn onto through
+/// [`lcd`](Self::lcd) in between writes and taken back with
+/// [`into_inner`](Self::into_inner). It doesn't touch the screen until
+/// something gets written; call [`clear`](Self::clear) to start with a blank
+/// one.
+///
+/// Every character occupies a cell 6 pixels wide and 8 pixels tall, so that
+/// every line of text covers exactly one row of chunks. Lines longer than
+/// [`COLUMNS`](Self::COLUMNS) characters get wrapped and writing past the last
+/// line scrolls the contents of the screen up by one line.
+///
+/// Nothing gets displayed until [`display_frame`](Self::display_frame) is
+/// called.
+pub struct Console<RST, SCE, DC, T> {
+    lcd: BufLcd10168<RST, SCE, DC, T>,
+    font: Font,
+    column: usize,
+    row: usize,
+}
+
+impl<RST: PinOps, SCE: PinOps, DC: PinOps, T: Transport> BufLcd10168<RST, SCE, DC, T> {
+    /// Returns a console writing onto this display, with the cursor in the
+    /// top-left corner of the screen.
+    pub fn into_console(self) -> Console<RST, SCE, DC, T> {
+        Console {
+            lcd: self,
+            font: Font::regular(),
+            column: 0,
+            row: 0,
+        }
+    }
+}
+
+impl<RST, SCE, DC, T> Console<RST, SCE, DC, T> {
+    const CELL_WIDTH: usize = 6;
+
+    pub const COLUMNS: usize = Lcd10168::COLUMNS / Self::CELL_WIDTH;
+    pub const ROWS: usize = Lcd10168::ROWS;
+
+    /// Returns the display, for drawing onto it in between writes.
+    #[inline]
+    pub fn lcd(&mut self) -> &mut BufLcd10168<RST, SCE, DC, T> {
+        &mut self.lcd
+    }
+
+    pub fn into_inner(self) -> BufLcd10168<RST, SCE, DC, T> {
+        self.lcd
+    }
+
+    /// Returns the column and the row of the cell the next character is going
+    /// to be written to.
+    pub fn cursor(&self) -> (usize, usize) {
+        (self.column, self.row)
+    }
+
+    /// Moves the cursor to the given cell, clamping it to the bounds of the
+    /// screen.
+    pub fn set_cursor(&mut self, column: usize, row: usize) {
+        self.column = column.min(Self::COLUMNS);
+        self.row = row.min(Self::ROWS - 1);
+    }
+
+    /// Clears the screen and moves the cursor to the top-left corner.
+    pub fn clear(&mut self) {
+        self.lcd.clear();
+        self.column = 0;
+        self.row = 0;
+    }
+
+    fn new_line(&mut self) {
+        self.column = 0;
+
+        if self.row + 1 < Self::ROWS {
+            self.row += 1;
+        } else {
+            self.scroll_up();
+        }
+    }
+
+    fn scroll_up(&mut self) {
+        let buffer = &mut self.lcd.buffer;
+        let last_row = buffer.len() - Lcd10168::COLUMNS;
+
+        buffer.copy_within(Lcd10168::COLUMNS.., 0);
+        buffer[last_row..].fill(Default::default());
+
+        self.lcd.invalidate();
+    }
+}
+
+impl<RST: PinOps, SCE: PinOps, DC: PinOps, T: Transport> Console<RST, SCE, DC, T> {
+    /// Writes a single character at the cursor and advances the cursor.
+    ///
+    /// `\n` moves the cursor to the beginning of the next line and `\r` moves
+    /// it to the beginning of the current line.
+    pub fn put_char(&mut self, c: char) {
+        match c {
+            '\n' => self.new_line(),
+            '\r' => self.column = 0,
+            c => {
+                if self.column == Self::COLUMNS {
+                    self.new_line();
+                }
+
+                let x = self.column * Self::CELL_WIDTH;
+
+                for column in x..x + Self::CELL_WIDTH {
+                    *self.lcd.chunk_at_raw(column, self.row) = Chunk::default();
+                }
+
+                let position: Vec2<isize> = Vec2::new(x, self.row * 8).into();
+                self.font.draw_glyph(&mut self.lcd, c, position, Color::On);
+
+                self.column += 1;
+            }
+        }
+    }
+
+    pub fn display_frame(&mut self) {
+        self.lcd.display_frame();
+    }
+}
+
+impl<RST: PinOps, SCE: PinOps, DC: PinOps, T: Transport> fmt::Write for Console<RST, SCE, DC, T> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            self.put_char(c);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use crate::lcd::Lcd10168;
+use core::ops::RangeInclusive;
+
+/// Keeps track of the columns of every row of chunks which have been modified
+/// since they were last sent to the display.
+///
+/// A single span of columns per row is enough to send every modified chunk
+/// with one cursor move, and takes only a couple of bytes of RAM.
+pub(super) struct DirtyRows {
+    rows: [Option<(u8, u8)>; Lcd10168::ROWS],
+}
+
+impl DirtyRows {
+    pub(super) fn all() -> Self {
+        Self {
+            rows: [Some((0, Lcd10168::COLUMNS as u8 - 1)); Lcd10168::ROWS],
+        }
+    }
+
+    /// Widens the span of the `row` so that it includes the `columns`.
+    pub(super) fn mark(&mut self, row: usize, columns: RangeInclusive<usize>) {
+        let (start, end) = (*columns.start() as u8, *columns.end() as u8);
+
+        self.rows[row] = match self.rows[row] {
+            Some((dirty_start, dirty_end)) => Some((dirty_start.min(start), dirty_end.max(end))),
+            None => Some((start, end)),
+        };
+    }
+
+    /// Returns the span of the `row`, marking the row as clean.
+    pub(super) fn take(&mut self, row: usize) -> Option<RangeInclusive<usize>> {
+        let (start, end) = self.rows[row].take()?;
+        Some(start as usize..=end as usize)
+    }
+}
@@ -0,0 +1,65 @@
+use super::{BufLcd10168, Chunk};
+use crate::lcd::{Lcd10168, Transport};
+use avr_hal_generic::port::PinOps;
+use core::convert::Infallible;
+use embedded_graphics_core::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, OriginDimensions, Size},
+    pixelcolor::BinaryColor,
+    primitives::Rectangle,
+    Pixel,
+};
+use hilton_graphics::canvas::{Canvas, Vec2};
+
+impl<RST, SCE, DC, T> OriginDimensions for BufLcd10168<RST, SCE, DC, T> {
+    #[inline]
+    fn size(&self) -> Size {
+        Size::new(Lcd10168::COLUMNS as u32, (Lcd10168::ROWS * 8) as u32)
+    }
+}
+
+impl<RST: PinOps, SCE: PinOps, DC: PinOps, T: Transport> DrawTarget
+    for BufLcd10168<RST, SCE, DC, T>
+{
+    type Color = BinaryColor;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let bounding_box = self.bounding_box();
+
+        for Pixel(point, color) in pixels {
+            // the coordinates have to fit in an `isize` after clipping
+            if bounding_box.contains(point) {
+                self.blit_pixel(point.x as isize, point.y as isize, color.into());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Fills the area one whole chunk at a time, instead of one pixel at a
+    /// time.
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let area = area.intersection(&self.bounding_box());
+        let position = Vec2::new(area.top_left.x as isize, area.top_left.y as isize);
+        let size = Vec2::new(area.size.width as isize, area.size.height as isize);
+
+        self.fill_rect(position, size, color.into());
+
+        Ok(())
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        let chunk = match color {
+            BinaryColor::Off => Chunk::from(0x00),
+            BinaryColor::On => Chunk::from(0xff),
+        };
+        self.buffer.fill(chunk);
+        self.invalidate();
+
+        Ok(())
+    }
+}
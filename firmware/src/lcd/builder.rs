@@ -1,11 +1,11 @@
 use super::*;
-use atmega_hal::{clock::MHz16, delay::Delay};
+use atmega_hal::{clock::MHz16, delay::Delay, Spi};
 use avr_hal_generic::port::{
     mode::{Io, Output},
     Pin, PinOps,
 };
 
-impl Lcd10168<Unconnected, Unconnected, Unconnected, Unconnected, Unconnected> {
+impl Lcd10168<Unconnected, Unconnected, Unconnected, Unconnected> {
     pub fn builder() -> UnconnectedLcd10168Builder {
         Lcd10168Builder::new()
     }
@@ -25,16 +25,15 @@ impl<PIN> Connected<PIN> {
     }
 }
 
-pub struct Lcd10168Builder<R, S, D, I, C> {
+pub struct Lcd10168Builder<R, S, D, T> {
     rst: R,
     sce: S,
     dc: D,
-    din: I,
-    clk: C,
+    transport: T,
 }
 
 pub type UnconnectedLcd10168Builder =
-    Lcd10168Builder<Unconnected, Unconnected, Unconnected, Unconnected, Unconnected>;
+    Lcd10168Builder<Unconnected, Unconnected, Unconnected, Unconnected>;
 
 impl UnconnectedLcd10168Builder {
     pub fn new() -> Self {
@@ -42,98 +41,90 @@ impl UnconnectedLcd10168Builder {
             rst: Unconnected,
             sce: Unconnected,
             dc: Unconnected,
-            din: Unconnected,
-            clk: Unconnected,
+            transport: Unconnected,
         }
     }
 }
 
-impl<S, D, I, C> Lcd10168Builder<Unconnected, S, D, I, C> {
+impl<S, D, T> Lcd10168Builder<Unconnected, S, D, T> {
     pub fn reset<MODE: Io, RST: PinOps>(
         self,
         pin: Pin<MODE, RST>,
-    ) -> Lcd10168Builder<Connected<RST>, S, D, I, C> {
+    ) -> Lcd10168Builder<Connected<RST>, S, D, T> {
         Lcd10168Builder {
             rst: Connected::new(pin.into_output()),
             sce: self.sce,
             dc: self.dc,
-            din: self.din,
-            clk: self.clk,
+            transport: self.transport,
         }
     }
 }
 
-impl<R, D, I, C> Lcd10168Builder<R, Unconnected, D, I, C> {
+impl<R, D, T> Lcd10168Builder<R, Unconnected, D, T> {
     pub fn chip_enable<MODE: Io, SCE: PinOps>(
         self,
         pin: Pin<MODE, SCE>,
-    ) -> Lcd10168Builder<R, Connected<SCE>, D, I, C> {
+    ) -> Lcd10168Builder<R, Connected<SCE>, D, T> {
         Lcd10168Builder {
             rst: self.rst,
             sce: Connected::new(pin.into_output()),
             dc: self.dc,
-            din: self.din,
-            clk: self.clk,
+            transport: self.transport,
         }
     }
 }
 
-impl<R, S, I, C> Lcd10168Builder<R, S, Unconnected, I, C> {
+impl<R, S, T> Lcd10168Builder<R, S, Unconnected, T> {
     pub fn data_command<MODE: Io, DC: PinOps>(
         self,
         pin: Pin<MODE, DC>,
-    ) -> Lcd10168Builder<R, S, Connected<DC>, I, C> {
+    ) -> Lcd10168Builder<R, S, Connected<DC>, T> {
         Lcd10168Builder {
             rst: self.rst,
             sce: self.sce,
             dc: Connected::new(pin.into_output()),
-            din: self.din,
-            clk: self.clk,
+            transport: self.transport,
         }
     }
 }
 
-impl<R, S, D, C> Lcd10168Builder<R, S, D, Unconnected, C> {
-    pub fn data_in<MODE: Io, DIN: PinOps>(
+impl<R, S, D> Lcd10168Builder<R, S, D, Unconnected> {
+    /// Sends the data by toggling the `data_in` and `clock` pins in software.
+    /// See [`BitBang`] for more details.
+    pub fn bit_banged<MODE1: Io, DIN: PinOps, MODE2: Io, CLK: PinOps>(
         self,
-        pin: Pin<MODE, DIN>,
-    ) -> Lcd10168Builder<R, S, D, Connected<DIN>, C> {
-        Lcd10168Builder {
-            rst: self.rst,
-            sce: self.sce,
-            dc: self.dc,
-            din: Connected::new(pin.into_output()),
-            clk: self.clk,
-        }
+        data_in: Pin<MODE1, DIN>,
+        clock: Pin<MODE2, CLK>,
+    ) -> Lcd10168Builder<R, S, D, BitBang<DIN, CLK>> {
+        self.transport(BitBang::new(data_in.into_output(), clock.into_output()))
     }
-}
 
-impl<R, S, D, I> Lcd10168Builder<R, S, D, I, Unconnected> {
-    pub fn clock<MODE: Io, CLK: PinOps>(
-        self,
-        pin: Pin<MODE, CLK>,
-    ) -> Lcd10168Builder<R, S, D, I, Connected<CLK>> {
+    /// Sends the data through the hardware SPI peripheral. See [`HardwareSpi`]
+    /// for more details.
+    pub fn hardware_spi(self, spi: Spi) -> Lcd10168Builder<R, S, D, HardwareSpi> {
+        self.transport(HardwareSpi::new(spi))
+    }
+
+    pub fn transport<T: Transport>(self, transport: T) -> Lcd10168Builder<R, S, D, T> {
         Lcd10168Builder {
             rst: self.rst,
             sce: self.sce,
             dc: self.dc,
-            din: self.din,
-            clk: Connected::new(pin.into_output()),
+            transport,
         }
     }
 }
 
-impl<RST, SCE, DC, DIN, CLK>
-    Lcd10168Builder<Connected<RST>, Connected<SCE>, Connected<DC>, Connected<DIN>, Connected<CLK>>
-{
-    pub fn build(self) -> Lcd10168<RST, SCE, DC, DIN, CLK> {
+impl<RST, SCE, DC, T: Transport> Lcd10168Builder<Connected<RST>, Connected<SCE>, Connected<DC>, T> {
+    pub fn build(self) -> Lcd10168<RST, SCE, DC, T> {
         Lcd10168 {
             rst: self.rst.into_inner(),
             sce: self.sce.into_inner(),
             dc: self.dc.into_inner(),
-            din: self.din.into_inner(),
-            clk: self.clk.into_inner(),
+            transport: self.transport,
             delay: Delay::<MHz16>::new(),
+            chip_mode: Default::default(),
+            addressing_mode: Default::default(),
         }
     }
 }
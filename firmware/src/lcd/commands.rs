@@ -29,3 +29,63 @@ pub enum DisplayMode {
     Filled = 0b001,
     Inverse = 0b101,
 }
+
+macro_rules! bounded {
+    ($($(#[$attr:meta])* $name:ident(..$end:literal)),* $(,)?) => {
+        $(
+            $(#[$attr])*
+            #[derive(Clone, Copy)]
+            pub struct $name(u8);
+
+            impl $name {
+                pub const MIN: Self = Self(0);
+                pub const MAX: Self = Self($end - 1);
+
+                /// Returns `None` if `value` is out of range.
+                pub const fn new(value: u8) -> Option<Self> {
+                    match value < $end {
+                        true => Some(Self(value)),
+                        false => None,
+                    }
+                }
+
+                pub const fn get(self) -> u8 {
+                    self.0
+                }
+            }
+        )*
+    };
+}
+
+bounded! {
+    /// The X address of the display RAM, less than 84.
+    XAddress(..84),
+    /// The Y address of the display RAM, less than 6.
+    YAddress(..6),
+    /// The bias system voltage coefficient, less than 8.
+    BiasCoefficient(..8),
+    /// The temperature coefficient, less than 4.
+    TemperatureCoefficient(..4),
+    /// The operation voltage, less than 128.
+    OperationVoltage(..128),
+}
+
+macro_rules! saturating {
+    ($($name:ident),* $(,)?) => {
+        $(
+            impl $name {
+                /// Returns [`MAX`](Self::MAX) if `value` is out of range.
+                pub(crate) const fn saturating(value: usize) -> Self {
+                    match value <= Self::MAX.0 as usize {
+                        true => Self(value as u8),
+                        false => Self::MAX,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+// the buffered display addresses its chunks with indices which always lie
+// within the display RAM
+saturating!(XAddress, YAddress);
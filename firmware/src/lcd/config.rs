@@ -0,0 +1,61 @@
+use super::{BiasCoefficient, Lcd10168, OperationVoltage, TemperatureCoefficient, Transport};
+use avr_hal_generic::port::PinOps;
+
+/// The settings of the voltages driving the display, which differ between the
+/// batches of panels.
+///
+/// The defaults suit most panels, so usually only some of the settings need
+/// changing, like `LcdConfig { contrast, ..LcdConfig::new() }`.
+#[derive(Clone, Copy)]
+pub struct LcdConfig {
+    /// The bias system voltage coefficient.
+    pub bias: BiasCoefficient,
+    /// The temperature coefficient of the operation voltage. The higher it is,
+    /// the more the voltage rises as the temperature drops, which keeps the
+    /// contrast steady in the cold.
+    pub temperature_coefficient: TemperatureCoefficient,
+    /// The operation voltage. The higher it is, the darker the pixels get.
+    pub contrast: OperationVoltage,
+}
+
+impl LcdConfig {
+    pub const fn new() -> Self {
+        const BIAS: BiasCoefficient = match BiasCoefficient::new(0) {
+            Some(bias) => bias,
+            None => panic!(),
+        };
+        const TEMPERATURE_COEFFICIENT: TemperatureCoefficient = match TemperatureCoefficient::new(0)
+        {
+            Some(coeff) => coeff,
+            None => panic!(),
+        };
+        const CONTRAST: OperationVoltage = match OperationVoltage::new(50) {
+            Some(contrast) => contrast,
+            None => panic!(),
+        };
+
+        Self {
+            bias: BIAS,
+            temperature_coefficient: TEMPERATURE_COEFFICIENT,
+            contrast: CONTRAST,
+        }
+    }
+}
+
+impl Default for LcdConfig {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<RST: PinOps, SCE: PinOps, DC: PinOps, T: Transport> Lcd10168<RST, SCE, DC, T> {
+    /// Sends all the settings of the `config` to the chip.
+    pub fn configure(&mut self, config: LcdConfig) {
+        self.extended(|lcd| {
+            lcd.set_bias_voltage_coefficient(config.bias);
+            lcd.set_temperature_coefficient(config.temperature_coefficient);
+            lcd.set_operation_voltage(config.contrast);
+        });
+    }
+}
@@ -0,0 +1,84 @@
+use atmega_hal::{port::Dynamic, Spi};
+use avr_hal_generic::port::{mode::Output, Pin, PinOps};
+use avr_hal_generic::prelude::*;
+
+/// A way of sending bytes to the chip, most significant bit first.
+pub trait Transport {
+    /// The type of the transport with its pins downgraded to dynamic pins.
+    type Dynamic: Transport;
+
+    fn write(&mut self, value: u8);
+
+    fn downgrade(self) -> Self::Dynamic;
+}
+
+/// Sends the bytes by toggling the data in and clock pins in software, one
+/// bit at a time.
+pub struct BitBang<DIN = Dynamic, CLK = Dynamic> {
+    din: Pin<Output, DIN>,
+    clk: Pin<Output, CLK>,
+}
+
+impl<DIN, CLK> BitBang<DIN, CLK> {
+    pub fn new(din: Pin<Output, DIN>, clk: Pin<Output, CLK>) -> Self {
+        Self { din, clk }
+    }
+}
+
+impl<DIN: PinOps, CLK: PinOps> Transport for BitBang<DIN, CLK> {
+    type Dynamic = BitBang<DIN::Dynamic, CLK::Dynamic>;
+
+    fn write(&mut self, value: u8) {
+        for i in 0..8 {
+            if ((value >> (7 - i)) & 1) != 0 {
+                self.din.set_high();
+            } else {
+                self.din.set_low();
+            }
+
+            self.clk.set_high();
+            self.clk.set_low();
+        }
+    }
+
+    fn downgrade(self) -> Self::Dynamic {
+        BitBang {
+            din: self.din.downgrade(),
+            clk: self.clk.downgrade(),
+        }
+    }
+}
+
+/// Sends the bytes through the hardware SPI peripheral, with the data in pin
+/// of the chip connected to MOSI (PB3) and the clock pin connected to SCK
+/// (PB5).
+///
+/// The peripheral has to be set up in mode 0 with the most significant bit
+/// first, and its clock mustn't be faster than the 4 MHz the chip supports.
+pub struct HardwareSpi {
+    spi: Spi,
+}
+
+impl HardwareSpi {
+    pub fn new(spi: Spi) -> Self {
+        Self { spi }
+    }
+
+    pub fn into_inner(self) -> Spi {
+        self.spi
+    }
+}
+
+impl Transport for HardwareSpi {
+    type Dynamic = Self;
+
+    fn write(&mut self, value: u8) {
+        // the peripheral never reports any errors
+        let _ = self.spi.write(&[value]);
+    }
+
+    #[inline]
+    fn downgrade(self) -> Self::Dynamic {
+        self
+    }
+}
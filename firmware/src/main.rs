@@ -2,14 +2,10 @@
 #![no_main]
 
 use atmega_hal::{pins, Peripherals};
+use hilton::lcd::{Lcd10168, LcdConfig};
 
-mod canvas;
-mod hilton;
-mod lcd;
 mod panic;
 
-use self::lcd::Lcd10168;
-
 #[atmega_hal::entry]
 fn main() -> ! {
     let dp = Peripherals::take().unwrap();
@@ -19,13 +15,12 @@ fn main() -> ! {
         .reset(pins.pc1)
         .chip_enable(pins.pc2)
         .data_command(pins.pc3)
-        .data_in(pins.pc4)
-        .clock(pins.pc5)
+        .bit_banged(pins.pc4, pins.pc5)
         .build()
         .into_buffered()
-        .init();
+        .init(LcdConfig::default());
 
-    hilton::draw(&mut lcd);
+    hilton_graphics::hilton::draw(&mut lcd);
 
     lcd.display_frame();
 
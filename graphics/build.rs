@@ -0,0 +1,117 @@
+//! Compresses the bitmaps in `assets` and generates the Rust source declaring
+//! them, which gets included by `canvas::draw::bitmap`.
+//!
+//! Every bitmap gets stored in program memory in whichever of the encodings
+//! is smaller for it. Setting the `HILTON_ASSET_SIZES` environment variable
+//! prints the raw and the compressed size of every bitmap during the build.
+
+use std::{env, error::Error, ffi::OsStr, fmt::Write as _, fs, path::Path};
+
+const ASSETS: &str = "assets";
+const MAX_RUN: usize = 15;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    println!("cargo:rerun-if-changed={ASSETS}");
+    println!("cargo:rerun-if-env-changed=HILTON_ASSET_SIZES");
+
+    let report = env::var_os("HILTON_ASSET_SIZES").is_some();
+    let mut paths = fs::read_dir(ASSETS)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    paths.retain(|path| path.extension() == Some(OsStr::new("png")));
+    paths.sort();
+
+    let mut source = String::new();
+
+    for path in paths {
+        let image = image::open(&path)?.to_luma8();
+        let (width, height) = image.dimensions();
+        let pixels = image
+            .pixels()
+            .map(|pixel| pixel.0[0] < 128)
+            .collect::<Vec<_>>();
+
+        let bits = encode_bits(&pixels);
+        let runs = encode_runs(&pixels);
+        let (encoding, data) = match runs.len() < bits.len() {
+            true => ("Runs", runs),
+            false => ("Bits", bits.clone()),
+        };
+
+        let name = path.file_stem().unwrap().to_string_lossy().to_uppercase();
+
+        if report {
+            println!(
+                "cargo:warning={}: {} bytes raw, {} bytes compressed ({})",
+                path.display(),
+                bits.len(),
+                data.len(),
+                encoding,
+            );
+        }
+
+        println!("cargo:rerun-if-changed={}", path.display());
+        writeln!(
+            source,
+            "#[cfg_attr(target_arch = \"avr\", link_section = \".progmem.data\")]\n\
+             static {name}_DATA: [u8; {}] = {data:?};\n\
+             static {name}: CompressedStamp = \
+             CompressedStamp::new({width}, {height}, Encoding::{encoding}, &{name}_DATA);",
+            data.len(),
+        )?;
+    }
+
+    let out_dir = env::var("OUT_DIR")?;
+    fs::write(Path::new(&out_dir).join("assets.rs"), source)?;
+
+    Ok(())
+}
+
+/// Packs the pixels row by row, eight pixels per byte, starting from the most
+/// significant bit.
+fn encode_bits(pixels: &[bool]) -> Vec<u8> {
+    pixels
+        .chunks(8)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .enumerate()
+                .fold(0, |byte, (i, &pixel)| byte | (pixel as u8) << (7 - i))
+        })
+        .collect()
+}
+
+/// Encodes the lengths of the alternating runs of white and black pixels, two
+/// runs per byte, starting from a white run in the high nibble. Runs longer
+/// than 15 pixels get split by runs of length 0 of the other color.
+fn encode_runs(pixels: &[bool]) -> Vec<u8> {
+    let mut nibbles = Vec::new();
+    let mut black = false;
+    let mut run = 0;
+
+    for &pixel in pixels {
+        if pixel != black {
+            push_run(&mut nibbles, run);
+            black = pixel;
+            run = 0;
+        }
+
+        run += 1;
+    }
+
+    push_run(&mut nibbles, run);
+
+    nibbles
+        .chunks(2)
+        .map(|pair| pair[0] << 4 | pair.get(1).copied().unwrap_or(0))
+        .collect()
+}
+
+fn push_run(nibbles: &mut Vec<u8>, mut run: usize) {
+    while run > MAX_RUN {
+        nibbles.extend([MAX_RUN as u8, 0]);
+        run -= MAX_RUN;
+    }
+
+    nibbles.push(run as u8);
+}
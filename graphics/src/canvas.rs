@@ -0,0 +1,110 @@
+use core::ops::RangeInclusive;
+
+mod animation;
+mod chunk_span;
+mod color;
+mod draw;
+#[cfg(feature = "embedded-graphics")]
+mod draw_target;
+mod framebuffer;
+mod geometry;
+mod paint;
+mod pattern;
+mod scene;
+mod style;
+mod text;
+mod translation;
+mod viewport;
+
+pub use self::animation::*;
+pub use self::chunk_span::*;
+pub use self::color::*;
+pub use self::draw::*;
+pub use self::framebuffer::*;
+pub use self::geometry::*;
+pub use self::paint::*;
+pub use self::pattern::*;
+pub use self::scene::*;
+pub use self::style::*;
+pub use self::text::*;
+pub use self::translation::*;
+pub use self::viewport::*;
+
+pub trait Canvas {
+    fn blit_pixel(&mut self, x: isize, y: isize, color: Color);
+
+    /// Blits the pixels of the row `y` in the `xs` columns.
+    fn blit_span(&mut self, xs: RangeInclusive<isize>, y: isize, color: Color) {
+        for x in xs {
+            self.blit_pixel(x, y, color);
+        }
+    }
+
+    /// Blits the pixels of the column `x` which are set in `chunk`, the least
+    /// significant bit being the pixel at `y` and the most significant bit
+    /// being the pixel 7 rows below it.
+    fn blit_chunk(&mut self, x: isize, y: isize, chunk: u8, color: Color) {
+        for i in 0..8 {
+            if chunk & 1 << i != 0 {
+                self.blit_pixel(x, y + i, color);
+            }
+        }
+    }
+
+    /// Blits every pixel of the rectangle with its top-left corner at
+    /// `position`. Draws nothing if either component of `size` isn't positive.
+    fn fill_rect(&mut self, position: Vec2<isize>, size: Vec2<isize>, color: Color) {
+        for y in position.y..position.y + size.y {
+            self.blit_span(position.x..=position.x + size.x - 1, y, color);
+        }
+    }
+
+    /// Blits a bitmap `width` pixels wide with its top-left corner at
+    /// `position`. The bitmap is made of `chunks` like the ones passed to
+    /// [`Canvas::blit_chunk`], laid out left to right in rows of chunks 8
+    /// pixels tall, from the top to the bottom.
+    fn blit_bitmap(
+        &mut self,
+        position: Vec2<isize>,
+        width: usize,
+        chunks: impl IntoIterator<Item = u8>,
+        color: Color,
+    ) {
+        if width == 0 {
+            return;
+        }
+
+        for (i, chunk) in chunks.into_iter().enumerate() {
+            let x = position.x + (i % width) as isize;
+            let y = position.y + (i / width * 8) as isize;
+
+            self.blit_chunk(x, y, chunk, color);
+        }
+    }
+
+    /// Returns a canvas drawing onto the `bounds` of this canvas. See
+    /// [`Viewport`] for more details.
+    fn viewport(&mut self, bounds: Bounds) -> Viewport<'_, Self>
+    where
+        Self: Sized,
+    {
+        Viewport::new(self, bounds)
+    }
+
+    /// Returns a canvas drawing onto this canvas with its origin moved by
+    /// `offset`. See [`Translation`] for more details.
+    fn translation(&mut self, offset: Vec2<isize>) -> Translation<'_, Self>
+    where
+        Self: Sized,
+    {
+        Translation::new(self, offset)
+    }
+}
+
+/// A canvas whose pixels can be read back.
+pub trait ReadableCanvas: Canvas {
+    /// Returns the color of the pixel at `(x, y)`, which is either
+    /// [`Color::On`] or [`Color::Off`], or `None` if the pixel lies outside of
+    /// the canvas.
+    fn get_pixel(&self, x: isize, y: isize) -> Option<Color>;
+}
@@ -0,0 +1,93 @@
+use super::*;
+
+/// A single frame of an [`Animation`], shown for `duration` milliseconds.
+#[derive(Clone, Copy)]
+pub struct Frame<D> {
+    drawable: D,
+    duration: u16,
+}
+
+impl<D> Frame<D> {
+    #[inline]
+    pub const fn new(drawable: D, duration: u16) -> Self {
+        Self { drawable, duration }
+    }
+}
+
+/// Cycles through a sequence of frames, looping back to the first one after
+/// the last one ends.
+///
+/// Every frame is drawn with the same type of drawable, like [`Bitmap`]s of
+/// consecutive poses, each with its own position.
+pub struct Animation<D, const N: usize> {
+    frames: [Frame<D>; N],
+    current: usize,
+    elapsed: u16,
+}
+
+impl<D, const N: usize> Animation<D, N> {
+    #[inline]
+    pub const fn new(frames: [Frame<D>; N]) -> Self {
+        Self {
+            frames,
+            current: 0,
+            elapsed: 0,
+        }
+    }
+
+    /// Returns the index of the frame being shown.
+    #[inline]
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    /// Goes back to the beginning of the first frame.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.current = 0;
+        self.elapsed = 0;
+    }
+
+    /// Moves the animation `dt` milliseconds forward, skipping as many frames
+    /// as needed. Frames with a duration of 0 are always skipped, and an
+    /// animation with no frames longer than that never moves.
+    pub fn advance(&mut self, dt: u16) {
+        let total = self
+            .frames
+            .iter()
+            .map(|frame| frame.duration as u32)
+            .sum::<u32>();
+
+        if total == 0 {
+            return;
+        }
+
+        // whole loops of the animation end up on the same frame
+        let mut elapsed = (self.elapsed as u32 + dt as u32) % total;
+
+        while elapsed >= self.frames[self.current].duration as u32 {
+            elapsed -= self.frames[self.current].duration as u32;
+            self.current = (self.current + 1) % N;
+        }
+
+        self.elapsed = elapsed as u16;
+    }
+}
+
+impl<D: Draw, const N: usize> Draw for Animation<D, N> {
+    /// Draws the drawable of the current frame. Draws nothing if there are no
+    /// frames.
+    fn draw(&self, canvas: &mut impl Canvas) {
+        if let Some(frame) = self.frames.get(self.current) {
+            frame.drawable.draw(canvas);
+        }
+    }
+
+    /// Returns the bounds of the current frame only.
+    fn bounds(&self) -> Bounds {
+        match self.frames.get(self.current) {
+            Some(frame) => frame.drawable.bounds(),
+            None => Bounds::EMPTY,
+        }
+    }
+}
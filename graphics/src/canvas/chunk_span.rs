@@ -0,0 +1,98 @@
+use super::Vec2;
+use core::ops::{Range, RangeInclusive};
+
+/// A run of chunks within a single row of chunks of a canvas which, just like
+/// the screen's memory, stores every column of 8 pixels in a byte, the topmost
+/// pixel in the least significant bit. `mask` selects the pixels of each chunk
+/// of the run.
+///
+/// The constructors take the `size` of the canvas in pixels, the height of
+/// which has to be a multiple of 8, and clip the chunks to it.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ChunkSpan {
+    pub row: usize,
+    pub columns: Range<usize>,
+    pub mask: u8,
+}
+
+impl ChunkSpan {
+    /// Returns the run covering the pixels of the row `y` in the `xs` columns,
+    /// as passed to [`Canvas::blit_span`](super::Canvas::blit_span), or `None`
+    /// if none of them lie on the canvas.
+    pub fn of_span(xs: RangeInclusive<isize>, y: isize, size: Vec2<usize>) -> Option<Self> {
+        let start = (*xs.start()).max(0);
+        let end = (*xs.end()).min(size.x as isize - 1);
+        let y = usize::try_from(y).ok().filter(|&y| y < size.y)?;
+
+        (start <= end).then(|| Self {
+            row: y / 8,
+            columns: start as usize..end as usize + 1,
+            mask: 1 << (y % 8),
+        })
+    }
+
+    /// Returns the at most two runs of a single chunk covering the pixels of
+    /// the `chunk`, as passed to
+    /// [`Canvas::blit_chunk`](super::Canvas::blit_chunk).
+    pub fn of_chunk(
+        x: isize,
+        y: isize,
+        chunk: u8,
+        size: Vec2<usize>,
+    ) -> impl Iterator<Item = Self> {
+        let x = usize::try_from(x).ok().filter(|&x| x < size.x);
+
+        // the chunk straddles two rows of chunks unless it's aligned to them
+        let shifted = (chunk as u16) << y.rem_euclid(8);
+        let row = y.div_euclid(8);
+
+        [(row, shifted as u8), (row + 1, (shifted >> 8) as u8)]
+            .into_iter()
+            .filter_map(move |(row, mask)| {
+                let x = x?;
+                let row = usize::try_from(row).ok().filter(|&row| row < size.y / 8)?;
+
+                (mask != 0).then(|| Self {
+                    row,
+                    columns: x..x + 1,
+                    mask,
+                })
+            })
+    }
+
+    /// Returns a run for every row of chunks overlapping the rectangle, as
+    /// passed to [`Canvas::fill_rect`](super::Canvas::fill_rect).
+    pub fn of_rect(
+        position: Vec2<isize>,
+        size: Vec2<isize>,
+        canvas_size: Vec2<usize>,
+    ) -> impl Iterator<Item = Self> {
+        let start = position.max(Vec2::new(0, 0));
+        let end = (position + size).min(canvas_size.into());
+
+        // `bottom` is the last row of pixels, unlike `end.y`
+        let (columns, top, bottom, rows) = match start.x < end.x && start.y < end.y {
+            true => {
+                let (top, bottom) = (start.y as usize, end.y as usize - 1);
+                (
+                    start.x as usize..end.x as usize,
+                    top,
+                    bottom,
+                    top / 8..bottom / 8 + 1,
+                )
+            }
+            false => (0..0, 0, 0, 0..0),
+        };
+
+        rows.map(move |row| {
+            let first_bit = top.max(row * 8) - row * 8;
+            let last_bit = bottom.min(row * 8 + 7) - row * 8;
+
+            Self {
+                row,
+                columns: columns.clone(),
+                mask: (0xff << first_bit) & (0xff >> (7 - last_bit)),
+            }
+        })
+    }
+}
@@ -0,0 +1,9 @@
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Off,
+    On,
+    /// Inverts the pixel it gets blitted onto. Parts of a drawing which
+    /// overlap each other, like the joints between the edges of a polygon's
+    /// outline, get inverted more than once.
+    Toggle,
+}
@@ -0,0 +1,67 @@
+use super::*;
+use core::ops::RangeInclusive;
+
+mod arc;
+mod bitmap;
+mod circle;
+mod ellipse;
+mod flood_fill;
+mod line;
+mod pixel;
+mod polygon;
+mod rect;
+mod rounded_rect;
+mod triangle;
+
+pub use self::arc::*;
+pub use self::bitmap::*;
+pub use self::circle::*;
+pub use self::ellipse::*;
+pub use self::flood_fill::*;
+pub use self::line::*;
+pub use self::pixel::*;
+pub use self::polygon::*;
+pub use self::rect::*;
+pub use self::rounded_rect::*;
+pub use self::triangle::*;
+
+pub trait Draw {
+    fn draw(&self, canvas: &mut impl Canvas);
+
+    /// Returns bounds containing every pixel the drawable may blit. They aren't
+    /// necessarily the smallest bounds possible, but they're empty if the
+    /// drawable is known not to blit anything.
+    fn bounds(&self) -> Bounds;
+}
+
+impl<D: Draw, const N: usize> Draw for [D; N] {
+    /// Draws the drawables one after another.
+    fn draw(&self, canvas: &mut impl Canvas) {
+        for drawable in self {
+            drawable.draw(canvas);
+        }
+    }
+
+    fn bounds(&self) -> Bounds {
+        self.iter()
+            .map(Draw::bounds)
+            .fold(Bounds::EMPTY, Bounds::union)
+    }
+}
+
+/// Fills the rows `dy` pixels above and below `center` between the `xs`
+/// offsets from the center. The middle row gets filled only once.
+fn fill_mirrored_rows(
+    canvas: &mut impl Canvas,
+    center: Vec2<isize>,
+    dy: isize,
+    xs: RangeInclusive<isize>,
+    paint: Paint,
+) {
+    let xs = center.x + xs.start()..=center.x + xs.end();
+    paint.blit_span(canvas, xs.clone(), center.y - dy);
+
+    if dy != 0 {
+        paint.blit_span(canvas, xs, center.y + dy);
+    }
+}
@@ -0,0 +1,123 @@
+use super::*;
+
+pub struct Arc {
+    center: Vec2<isize>,
+    radius: isize,
+    start: isize,
+    end: isize,
+    style: Style,
+}
+
+impl Arc {
+    /// Creates an arc of a circle going counterclockwise from the `start`
+    /// angle to the `end` angle, both in degrees. An angle of 0 points to the
+    /// right and an angle of 90 points up.
+    #[inline]
+    pub fn new(
+        center: Vec2<isize>,
+        radius: isize,
+        start: isize,
+        end: isize,
+        style: impl Into<Style>,
+    ) -> Self {
+        Self {
+            center,
+            radius,
+            start,
+            end,
+            style: style.into(),
+        }
+    }
+}
+
+impl Draw for Arc {
+    /// Draws the pixels of a [`Circle`] of the same radius and style which lie
+    /// between the two angles of the arc.
+    ///
+    /// A filled arc is a pie segment, while an outlined arc covers only the
+    /// curved part of the segment's outline. Arcs spanning 360 degrees or more
+    /// are full circles.
+    fn draw(&self, canvas: &mut impl Canvas) {
+        let circle = Circle::new(self.center, self.radius, self.style);
+
+        if self.end - self.start >= 360 {
+            circle.draw(canvas);
+            return;
+        }
+
+        let sweep = (self.end - self.start).rem_euclid(360);
+
+        if sweep == 0 {
+            return;
+        }
+
+        circle.draw(&mut Sector {
+            canvas,
+            center: self.center,
+            start: direction(self.start),
+            end: direction(self.end),
+            is_reflex: sweep > 180,
+        });
+    }
+
+    /// Returns the bounds of the whole circle the arc is a part of.
+    fn bounds(&self) -> Bounds {
+        let sweep = self.end - self.start;
+
+        match sweep < 360 && sweep.rem_euclid(360) == 0 {
+            true => Bounds::EMPTY,
+            false => Circle::new(self.center, self.radius, self.style).bounds(),
+        }
+    }
+}
+
+/// A canvas which passes through only the pixels lying within a circular
+/// sector, bounded by the `start` and `end` directions.
+struct Sector<'a, C> {
+    canvas: &'a mut C,
+    center: Vec2<isize>,
+    start: Vec2<i32>,
+    end: Vec2<i32>,
+    is_reflex: bool,
+}
+
+impl<C: Canvas> Canvas for Sector<'_, C> {
+    fn blit_pixel(&mut self, x: isize, y: isize, color: Color) {
+        // flip the Y axis so that the angles go counterclockwise on the screen
+        let offset = Vec2::new((x - self.center.x) as i32, (self.center.y - y) as i32);
+        let after_start = cross(self.start, offset) >= 0;
+        let before_end = cross(offset, self.end) >= 0;
+
+        let is_inside = match self.is_reflex {
+            false => after_start && before_end,
+            true => after_start || before_end,
+        };
+
+        if is_inside {
+            self.canvas.blit_pixel(x, y, color);
+        }
+    }
+}
+
+fn cross(a: Vec2<i32>, b: Vec2<i32>) -> i32 {
+    a.x * b.y - a.y * b.x
+}
+
+/// Returns the unit vector pointing in the direction of `angle` in degrees,
+/// scaled by a factor of 256.
+fn direction(angle: isize) -> Vec2<i32> {
+    Vec2::new(sin(angle as i32 + 90), sin(angle as i32))
+}
+
+/// Approximates the sine of `angle` in degrees, scaled by a factor of 256,
+/// using the Bhaskara I's sine approximation formula.
+fn sin(angle: i32) -> i32 {
+    let angle = angle.rem_euclid(360);
+    let (angle, sign) = match angle > 180 {
+        false => (angle, 1),
+        true => (angle - 180, -1),
+    };
+    let product = angle * (180 - angle);
+
+    sign * 4 * 256 * product / (40500 - product)
+}
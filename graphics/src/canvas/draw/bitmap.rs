@@ -0,0 +1,314 @@
+use super::*;
+use stockbook::Stamp;
+
+mod compressed;
+mod overlap;
+
+pub use self::compressed::*;
+
+// generated by the build script from the images in `assets`
+include!(concat!(env!("OUT_DIR"), "/assets.rs"));
+
+macro_rules! bitmaps {
+    ($($name:ident($stamp:ident)),* $(,)?) => {
+        $(
+            impl Bitmap {
+                pub fn $name(position: Vec2<isize>) -> Self {
+                    Self::compressed(&$stamp, position)
+                }
+            }
+        )*
+    };
+}
+
+bitmaps! {
+    ear(EAR),
+    strand(STRAND),
+    whiskers(WHISKERS),
+    torso(TORSO),
+    tail(TAIL),
+}
+
+pub struct Bitmap {
+    source: Source,
+    position: Vec2<isize>,
+    flip_h: bool,
+    flip_v: bool,
+    quarter_turns: u8,
+    scale: usize,
+    color: Color,
+    mode: Mode,
+}
+
+/// The pixels of a [`Bitmap`] or of its mask, either raw or compressed.
+#[derive(Clone, Copy)]
+pub enum Source {
+    Raw(&'static Stamp),
+    Compressed(&'static CompressedStamp),
+}
+
+impl Source {
+    fn size(&self) -> [usize; 2] {
+        match self {
+            Source::Raw(stamp) => stamp.size(),
+            Source::Compressed(stamp) => stamp.size(),
+        }
+    }
+
+    fn reader(&self) -> Reader {
+        match *self {
+            Source::Raw(stamp) => Reader::Raw(stamp),
+            Source::Compressed(stamp) => Reader::Compressed(stamp.reader()),
+        }
+    }
+}
+
+impl From<&'static Stamp> for Source {
+    #[inline]
+    fn from(stamp: &'static Stamp) -> Self {
+        Source::Raw(stamp)
+    }
+}
+
+impl From<&'static CompressedStamp> for Source {
+    #[inline]
+    fn from(stamp: &'static CompressedStamp) -> Self {
+        Source::Compressed(stamp)
+    }
+}
+
+/// Reads the pixels of a [`Source`] in any order. See [`CompressedReader`] for
+/// the orders which are fast for compressed bitmaps.
+enum Reader {
+    Raw(&'static Stamp),
+    Compressed(CompressedReader<'static>),
+}
+
+impl Reader {
+    fn get_pixel(&mut self, u: usize, v: usize) -> Option<stockbook::Color> {
+        match self {
+            Reader::Raw(stamp) => stamp.get_pixel(u, v),
+            Reader::Compressed(reader) => reader.get_pixel(u, v),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Mode {
+    Transparent,
+    Opaque,
+    Masked(Source),
+}
+
+impl Bitmap {
+    pub fn new(stamp: &'static Stamp, position: Vec2<isize>) -> Self {
+        Self::with_source(Source::Raw(stamp), position)
+    }
+
+    pub fn compressed(stamp: &'static CompressedStamp, position: Vec2<isize>) -> Self {
+        Self::with_source(Source::Compressed(stamp), position)
+    }
+
+    fn with_source(source: Source, position: Vec2<isize>) -> Self {
+        Self {
+            source,
+            position,
+            flip_h: false,
+            flip_v: false,
+            quarter_turns: 0,
+            scale: 1,
+            color: Color::On,
+            mode: Mode::Transparent,
+        }
+    }
+}
+
+impl Bitmap {
+    /// Flips the bitmap horizontally, as it appears on the screen after all
+    /// the rotations applied so far.
+    pub fn flip_h(mut self) -> Self {
+        match self.quarter_turns % 2 {
+            0 => self.flip_h = !self.flip_h,
+            _ => self.flip_v = !self.flip_v,
+        }
+        self
+    }
+
+    /// Flips the bitmap vertically, as it appears on the screen after all the
+    /// rotations applied so far.
+    pub fn flip_v(mut self) -> Self {
+        match self.quarter_turns % 2 {
+            0 => self.flip_v = !self.flip_v,
+            _ => self.flip_h = !self.flip_h,
+        }
+        self
+    }
+
+    /// Rotates the bitmap clockwise by 90 degrees.
+    pub fn rotate_90(self) -> Self {
+        self.rotate(1)
+    }
+
+    /// Rotates the bitmap by 180 degrees.
+    pub fn rotate_180(self) -> Self {
+        self.rotate(2)
+    }
+
+    /// Rotates the bitmap clockwise by 270 degrees.
+    pub fn rotate_270(self) -> Self {
+        self.rotate(3)
+    }
+
+    /// Draws every pixel of the bitmap as a square of `factor` by `factor`
+    /// pixels.
+    pub fn scale(mut self, factor: usize) -> Self {
+        self.scale = factor;
+        self
+    }
+
+    /// Sets the color the black pixels of the bitmap get drawn with.
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Makes the white pixels of the bitmap clear the canvas, instead of
+    /// leaving it untouched.
+    pub fn opaque(mut self) -> Self {
+        self.mode = Mode::Opaque;
+        self
+    }
+
+    /// Draws only the pixels which are black in `mask`, which must be of the
+    /// same size as the bitmap and can be compressed or not, regardless of the
+    /// bitmap. The white pixels of the bitmap which get drawn clear the canvas.
+    pub fn masked(mut self, mask: impl Into<Source>) -> Self {
+        self.mode = Mode::Masked(mask.into());
+        self
+    }
+}
+
+impl Bitmap {
+    fn rotate(mut self, quarter_turns: u8) -> Self {
+        self.quarter_turns = (self.quarter_turns + quarter_turns) % 4;
+        self
+    }
+}
+
+impl Draw for Bitmap {
+    /// Draws the bitmap with the top-left corner of its flipped, rotated and
+    /// scaled image at `position`.
+    fn draw(&self, canvas: &mut impl Canvas) {
+        match self.source {
+            Source::Raw(stamp) => self.draw_pixels(canvas, stamp.size(), stamp.pixels()),
+            Source::Compressed(stamp) => self.draw_pixels(canvas, stamp.size(), stamp.pixels()),
+        }
+    }
+
+    fn bounds(&self) -> Bounds {
+        let [width, height] = self.source.size();
+        let size = match self.quarter_turns % 2 {
+            0 => Vec2::new(width, height),
+            _ => Vec2::new(height, width),
+        };
+
+        Bounds::new(self.position, Vec2::from(size * self.scale))
+    }
+}
+
+impl Bitmap {
+    /// Draws consecutive pixels of the same color within a row of the bitmap
+    /// as a single rectangle.
+    fn draw_pixels(
+        &self,
+        canvas: &mut impl Canvas,
+        size: [usize; 2],
+        pixels: impl Iterator<Item = (usize, usize, stockbook::Color)>,
+    ) {
+        let mut mask = match self.mode {
+            // the mask gets read in the same order as the bitmap gets decoded
+            Mode::Masked(mask) => Some(mask.reader()),
+            _ => None,
+        };
+        // the row, the first and the last column and the color of the run
+        let mut run: Option<(usize, usize, usize, Color)> = None;
+
+        for (u, v, color) in pixels {
+            let is_masked_out = match &mut mask {
+                Some(mask) => !matches!(mask.get_pixel(u, v), Some(stockbook::Color::Black)),
+                None => false,
+            };
+            let color = match (color, self.mode) {
+                _ if is_masked_out => None,
+                (stockbook::Color::Black, _) => Some(self.color),
+                (_, Mode::Transparent) => None,
+                (_, Mode::Opaque | Mode::Masked(_)) => Some(Color::Off),
+            };
+
+            match (&mut run, color) {
+                (Some((run_v, _, end, run_color)), Some(color))
+                    if *run_v == v && *end + 1 == u && *run_color == color =>
+                {
+                    *end = u;
+                }
+                (_, color) => {
+                    if let Some(run) = run {
+                        self.fill_run(canvas, size, run);
+                    }
+
+                    run = color.map(|color| (v, u, u, color));
+                }
+            }
+        }
+
+        if let Some(run) = run {
+            self.fill_run(canvas, size, run);
+        }
+    }
+
+    fn fill_run(
+        &self,
+        canvas: &mut impl Canvas,
+        size: [usize; 2],
+        (v, start, end, color): (usize, usize, usize, Color),
+    ) {
+        // the run stays a straight line after flipping and rotating it, so its
+        // ends are enough to tell where it ends up
+        let (x0, y0) = self.transform(size, start, v);
+        let (x1, y1) = self.transform(size, end, v);
+        let (left, top) = (x0.min(x1), y0.min(y1));
+        let (right, bottom) = (x0.max(x1), y0.max(y1));
+
+        canvas.fill_rect(
+            Vec2::new(
+                self.position.x + (left * self.scale) as isize,
+                self.position.y + (top * self.scale) as isize,
+            ),
+            Vec2::new(
+                ((right - left + 1) * self.scale) as isize,
+                ((bottom - top + 1) * self.scale) as isize,
+            ),
+            color,
+        );
+    }
+
+    /// Returns where the pixel at `(u, v)` ends up after flipping and rotating
+    /// the bitmap, before scaling it.
+    fn transform(&self, [width, height]: [usize; 2], u: usize, v: usize) -> (usize, usize) {
+        let u = match self.flip_h {
+            false => u,
+            true => width - u - 1,
+        };
+        let v = match self.flip_v {
+            false => v,
+            true => height - v - 1,
+        };
+
+        match self.quarter_turns {
+            0 => (u, v),
+            1 => (height - v - 1, u),
+            2 => (width - u - 1, height - v - 1),
+            _ => (v, width - u - 1),
+        }
+    }
+}
@@ -0,0 +1,228 @@
+#[cfg(target_arch = "avr")]
+use avr_progmem::raw::read_byte;
+use stockbook::Color;
+
+/// Outside of AVR, program memory is no different from any other memory.
+#[cfg(not(target_arch = "avr"))]
+unsafe fn read_byte(address: *const u8) -> u8 {
+    *address
+}
+
+/// A bitmap compressed at build time and stored in program memory. Created
+/// by the build script for every image in `assets`.
+pub struct CompressedStamp {
+    width: usize,
+    height: usize,
+    encoding: Encoding,
+    data: &'static [u8],
+}
+
+/// How the pixels of a [`CompressedStamp`] are stored. The build script
+/// picks whichever is smaller for a given image.
+#[derive(Clone, Copy)]
+pub(super) enum Encoding {
+    /// Eight pixels per byte, row by row, starting from the most significant
+    /// bit.
+    Bits,
+    /// The lengths of the alternating runs of white and black pixels, two
+    /// runs per byte, starting from a white run in the high nibble.
+    Runs,
+}
+
+impl CompressedStamp {
+    /// `data` must be stored in program memory.
+    #[inline]
+    pub(super) const fn new(
+        width: usize,
+        height: usize,
+        encoding: Encoding,
+        data: &'static [u8],
+    ) -> Self {
+        Self {
+            width,
+            height,
+            encoding,
+            data,
+        }
+    }
+
+    #[inline]
+    pub fn size(&self) -> [usize; 2] {
+        [self.width, self.height]
+    }
+
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the number of bytes the bitmap takes up in program memory.
+    #[inline]
+    pub fn compressed_size(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns the color of the pixel at `(x, y)`, or `None` if it lies outside
+    /// of the bitmap. Bitmaps stored as runs get decoded from the beginning up
+    /// to the pixel, use [`CompressedStamp::reader`] to read many pixels.
+    pub fn get_pixel(&self, x: usize, y: usize) -> Option<Color> {
+        self.reader().get_pixel(x, y)
+    }
+
+    /// Returns a reader of the pixels of the bitmap, which is much faster than
+    /// [`CompressedStamp::get_pixel`] at reading pixels in the order they're
+    /// stored in.
+    #[inline]
+    pub fn reader(&self) -> CompressedReader<'_> {
+        CompressedReader {
+            row: self.pixels(),
+            pixel: self.pixels(),
+        }
+    }
+
+    /// Returns an iterator decoding the pixels of the bitmap row by row, in
+    /// the same order as [`stockbook::Stamp::pixels`].
+    #[inline]
+    pub fn pixels(&self) -> CompressedPixels<'_> {
+        CompressedPixels {
+            stamp: self,
+            index: 0,
+            runs_read: 0,
+            run_left: 0,
+            run_is_black: false,
+        }
+    }
+
+    fn byte(&self, index: usize) -> u8 {
+        // SAFETY: the data is stored in program memory and the index is
+        // bounds-checked
+        unsafe { read_byte(&self.data[index]) }
+    }
+}
+
+#[derive(Clone)]
+pub struct CompressedPixels<'a> {
+    stamp: &'a CompressedStamp,
+    index: usize,
+    runs_read: usize,
+    run_left: u8,
+    run_is_black: bool,
+}
+
+impl Iterator for CompressedPixels<'_> {
+    type Item = (usize, usize, Color);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let stamp = self.stamp;
+
+        if self.index >= stamp.width * stamp.height {
+            return None;
+        }
+
+        let color = match stamp.encoding {
+            Encoding::Bits => bit_color(stamp.byte(self.index / 8), self.index),
+            Encoding::Runs => {
+                // runs of length 0 either split runs too long for a nibble or
+                // come first in bitmaps starting with a black pixel
+                while self.run_left == 0 {
+                    if self.runs_read >= stamp.data.len() * 2 {
+                        return None;
+                    }
+
+                    // white runs are always in the high nibbles
+                    let byte = stamp.byte(self.runs_read / 2);
+                    (self.run_left, self.run_is_black) = match self.runs_read % 2 {
+                        0 => (byte >> 4, false),
+                        _ => (byte & 0x0f, true),
+                    };
+                    self.runs_read += 1;
+                }
+
+                self.run_left -= 1;
+
+                match self.run_is_black {
+                    true => Color::Black,
+                    false => Color::White,
+                }
+            }
+        };
+
+        let (u, v) = (self.index % stamp.width, self.index / stamp.width);
+        self.index += 1;
+
+        Some((u, v, color))
+    }
+}
+
+impl CompressedPixels<'_> {
+    /// Skips the pixels preceding the one with the given index. Does nothing if
+    /// that pixel has already been decoded.
+    fn skip_to(&mut self, index: usize) {
+        if let Some(n) = index.checked_sub(self.index + 1) {
+            self.nth(n);
+        }
+    }
+}
+
+/// Reads the pixels of a [`CompressedStamp`] in any order, created with
+/// [`CompressedStamp::reader`].
+///
+/// Bitmaps stored as runs can only be decoded front to back, so the reader
+/// keeps its place both after the pixel it has read last and at the start of
+/// the row of that pixel. Reading the pixels row by row decodes every pixel
+/// once, and going back within the row only decodes that row again. Only
+/// going back to one of the previous rows decodes the bitmap from the
+/// beginning.
+pub struct CompressedReader<'a> {
+    row: CompressedPixels<'a>,
+    pixel: CompressedPixels<'a>,
+}
+
+impl CompressedReader<'_> {
+    /// Returns the color of the pixel at `(x, y)`, or `None` if it lies outside
+    /// of the bitmap.
+    pub fn get_pixel(&mut self, x: usize, y: usize) -> Option<Color> {
+        let stamp = self.pixel.stamp;
+
+        if x >= stamp.width || y >= stamp.height {
+            return None;
+        }
+
+        let row = y * stamp.width;
+        let index = row + x;
+
+        if let Encoding::Bits = stamp.encoding {
+            return Some(bit_color(stamp.byte(index / 8), index));
+        }
+
+        if index < self.pixel.index {
+            if row < self.row.index {
+                self.row = stamp.pixels();
+            }
+
+            self.pixel = self.row.clone();
+        }
+
+        if self.pixel.index <= row {
+            self.pixel.skip_to(row);
+            self.row = self.pixel.clone();
+        }
+
+        self.pixel.skip_to(index);
+        self.pixel.next().map(|(_, _, color)| color)
+    }
+}
+
+/// Returns the color of the pixel with the given index from the byte storing
+/// it in a bitmap stored as bits.
+fn bit_color(byte: u8, index: usize) -> Color {
+    match byte & (0x80 >> (index % 8)) {
+        0 => Color::White,
+        _ => Color::Black,
+    }
+}
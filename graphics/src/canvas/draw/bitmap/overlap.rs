@@ -0,0 +1,96 @@
+use super::*;
+
+impl Bitmap {
+    /// Returns whether any black pixel of this bitmap would get drawn over a
+    /// black pixel of `other`, taking the positions, the transformations and
+    /// the masks of both bitmaps into account.
+    pub fn overlaps(&self, other: &Bitmap) -> bool {
+        let (mut this, mut other) = (Solid::new(self), Solid::new(other));
+
+        any_point(self.bounds().intersect(other.bitmap.bounds()), |x, y| {
+            this.is_solid_at(x, y) && other.is_solid_at(x, y)
+        })
+    }
+
+    /// Returns whether any black pixel of this bitmap would get drawn over a
+    /// pixel of `canvas` which is on.
+    pub fn overlaps_canvas(&self, canvas: &impl ReadableCanvas) -> bool {
+        let mut this = Solid::new(self);
+
+        any_point(self.bounds(), |x, y| {
+            this.is_solid_at(x, y) && canvas.get_pixel(x, y) == Some(Color::On)
+        })
+    }
+}
+
+/// Returns whether `test` holds for any of the points within the bounds,
+/// checking them row by row, just like the bitmaps get read the fastest.
+fn any_point(bounds: Bounds, mut test: impl FnMut(isize, isize) -> bool) -> bool {
+    let end = bounds.end();
+
+    (bounds.position.y..end.y).any(|y| (bounds.position.x..end.x).any(|x| test(x, y)))
+}
+
+/// Reads back which pixels the black pixels of a bitmap would get drawn onto.
+struct Solid<'a> {
+    bitmap: &'a Bitmap,
+    source: Reader,
+    mask: Option<Reader>,
+}
+
+impl<'a> Solid<'a> {
+    fn new(bitmap: &'a Bitmap) -> Self {
+        Self {
+            bitmap,
+            source: bitmap.source.reader(),
+            mask: match bitmap.mode {
+                Mode::Masked(mask) => Some(mask.reader()),
+                _ => None,
+            },
+        }
+    }
+
+    /// Returns whether a black pixel of the bitmap would get drawn at `(x, y)`.
+    fn is_solid_at(&mut self, x: isize, y: isize) -> bool {
+        let bitmap = self.bitmap;
+        let [width, height] = bitmap.source.size();
+        let (x, y) = match (
+            usize::try_from(x - bitmap.position.x),
+            usize::try_from(y - bitmap.position.y),
+        ) {
+            (Ok(x), Ok(y)) if bitmap.scale > 0 => (x / bitmap.scale, y / bitmap.scale),
+            _ => return false,
+        };
+        let (columns, rows) = match bitmap.quarter_turns % 2 {
+            0 => (width, height),
+            _ => (height, width),
+        };
+
+        if x >= columns || y >= rows {
+            return false;
+        }
+
+        // the inverse of `Bitmap::transform`
+        let (u, v) = match bitmap.quarter_turns {
+            0 => (x, y),
+            1 => (y, height - x - 1),
+            2 => (width - x - 1, height - y - 1),
+            _ => (width - y - 1, x),
+        };
+        let u = match bitmap.flip_h {
+            false => u,
+            true => width - u - 1,
+        };
+        let v = match bitmap.flip_v {
+            false => v,
+            true => height - v - 1,
+        };
+
+        let is_masked_in = match &mut self.mask {
+            Some(mask) => matches!(mask.get_pixel(u, v), Some(stockbook::Color::Black)),
+            None => true,
+        };
+
+        is_masked_in && matches!(self.source.get_pixel(u, v), Some(stockbook::Color::Black))
+    }
+}
@@ -0,0 +1,175 @@
+use super::*;
+
+pub struct Circle {
+    center: Vec2<isize>,
+    radius: isize,
+    style: Style,
+}
+
+impl Circle {
+    #[inline]
+    pub fn new(center: Vec2<isize>, radius: isize, style: impl Into<Style>) -> Self {
+        Self {
+            center,
+            radius,
+            style: style.into(),
+        }
+    }
+}
+
+impl Draw for Circle {
+    /// Draws a circle using the Bresenham's circle algorithm.
+    ///
+    /// The circle's diameter in pixels is always an odd number and is
+    /// `radius * 2 + 1`. Negative values of `radius` are valid -- the circle
+    /// gets drawn the same way it would have if the sign was flipped.
+    ///
+    /// The outline of the circle covers exactly the pixels of the filled
+    /// circle which aren't covered by a filled circle with a radius smaller by
+    /// the width of the outline.
+    fn draw(&self, canvas: &mut impl Canvas) {
+        let radius = self.radius.abs();
+
+        match self.style.outline() {
+            Some((paint, width)) => {
+                if let Some(fill) = self.style.fill() {
+                    fill_circle(canvas, self.center, radius - width, fill);
+                }
+
+                fill_ring(canvas, self.center, radius, radius - width, paint);
+            }
+            None => {
+                if let Some(fill) = self.style.fill() {
+                    fill_circle(canvas, self.center, radius, fill);
+                }
+            }
+        }
+    }
+
+    fn bounds(&self) -> Bounds {
+        let radius = self.radius.abs();
+
+        Bounds::new(
+            self.center - Vec2::new(radius, radius),
+            Vec2::new(radius * 2 + 1, radius * 2 + 1),
+        )
+    }
+}
+
+fn fill_circle(canvas: &mut impl Canvas, center: Vec2<isize>, radius: isize, paint: Paint) {
+    let mut x = 0;
+    let mut y = radius;
+    let mut error = 5 - 4 * y;
+
+    while x <= y {
+        fill_mirrored_rows(canvas, center, x, -y..=y, paint);
+
+        if error > 0 {
+            if x != y {
+                fill_mirrored_rows(canvas, center, y, -x..=x, paint);
+            }
+
+            y -= 1;
+            error -= 8 * y;
+        }
+
+        x += 1;
+        error += 8 * x + 4;
+    }
+}
+
+/// Fills the pixels of a circle with the radius of `outer` which aren't
+/// covered by a circle with the radius of `inner`.
+///
+/// Both circles are traced simultaneously one octant at a time, column by
+/// column, and every pixel in between them gets mirrored to the remaining
+/// octants.
+fn fill_ring(
+    canvas: &mut impl Canvas,
+    center: Vec2<isize>,
+    outer: isize,
+    inner: isize,
+    paint: Paint,
+) {
+    let mut inner = Octant::new(inner);
+
+    for Vec2 { x, y } in Octant::new(outer) {
+        let start = match inner.next() {
+            Some(inner) => inner.y + 1,
+            None => x,
+        };
+
+        for y in start..=y {
+            blit_mirrored(canvas, center, x, y, paint);
+        }
+    }
+}
+
+/// Blits the pixel at the `(x, y)` offset from the center of the circle,
+/// along with its reflections across both axes and both diagonals. Every
+/// pixel gets blitted only once, even if some of the reflections overlap.
+fn blit_mirrored(canvas: &mut impl Canvas, center: Vec2<isize>, x: isize, y: isize, paint: Paint) {
+    let mut blit = |x, y| paint.blit_pixel(canvas, center.x + x, center.y + y);
+
+    if x == 0 && y == 0 {
+        blit(0, 0);
+    } else if x == 0 {
+        blit(0, -y);
+        blit(0, y);
+        blit(-y, 0);
+        blit(y, 0);
+    } else if x == y {
+        blit(-x, -x);
+        blit(x, -x);
+        blit(-x, x);
+        blit(x, x);
+    } else {
+        for (x, y) in [(x, y), (y, x)] {
+            blit(-x, -y);
+            blit(x, -y);
+            blit(-x, y);
+            blit(x, y);
+        }
+    }
+}
+
+/// Traces the boundary of a circle's octant, starting at the bottom of the
+/// circle and going counterclockwise, yielding one point for every column.
+/// Yields nothing for negative values of `radius`.
+struct Octant {
+    x: isize,
+    y: isize,
+    error: isize,
+}
+
+impl Octant {
+    fn new(radius: isize) -> Self {
+        Self {
+            x: 0,
+            y: radius,
+            error: 5 - 4 * radius,
+        }
+    }
+}
+
+impl Iterator for Octant {
+    type Item = Vec2<isize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.x > self.y {
+            return None;
+        }
+
+        let point = Vec2::new(self.x, self.y);
+
+        if self.error > 0 {
+            self.y -= 1;
+            self.error -= 8 * self.y;
+        }
+
+        self.x += 1;
+        self.error += 8 * self.x + 4;
+
+        Some(point)
+    }
+}
@@ -0,0 +1,169 @@
+use super::*;
+
+pub struct Ellipse {
+    center: Vec2<isize>,
+    radii: Vec2<isize>,
+    style: Style,
+}
+
+impl Ellipse {
+    #[inline]
+    pub fn new(center: Vec2<isize>, radii: Vec2<isize>, style: impl Into<Style>) -> Self {
+        Self {
+            center,
+            radii,
+            style: style.into(),
+        }
+    }
+}
+
+impl Draw for Ellipse {
+    /// Draws an ellipse using the midpoint ellipse algorithm.
+    ///
+    /// The ellipse's width and height in pixels are always odd numbers and are
+    /// `radii.x * 2 + 1` and `radii.y * 2 + 1` respectively. Negative radii are
+    /// treated the same way as in [`Circle`] and an ellipse with both radii
+    /// equal is indistinguishable from a circle.
+    ///
+    /// The outline of the ellipse covers exactly the pixels of the filled
+    /// ellipse which aren't covered by a filled ellipse with both radii smaller
+    /// by the width of the outline.
+    fn draw(&self, canvas: &mut impl Canvas) {
+        let radii = Vec2::new(self.radii.x.abs(), self.radii.y.abs());
+
+        match self.style.outline() {
+            Some((paint, width)) => {
+                let inner = Vec2::new(radii.x - width, radii.y - width);
+
+                if let Some(fill) = self.style.fill() {
+                    fill_ellipse(canvas, self.center, inner, fill);
+                }
+
+                fill_ring(canvas, self.center, radii, inner, paint);
+            }
+            None => {
+                if let Some(fill) = self.style.fill() {
+                    fill_ellipse(canvas, self.center, radii, fill);
+                }
+            }
+        }
+    }
+
+    fn bounds(&self) -> Bounds {
+        let radii = Vec2::new(self.radii.x.abs(), self.radii.y.abs());
+
+        Bounds::new(self.center - radii, radii * 2 + Vec2::new(1, 1))
+    }
+}
+
+fn fill_ellipse(canvas: &mut impl Canvas, center: Vec2<isize>, radii: Vec2<isize>, paint: Paint) {
+    for (dy, half_width) in Rows::new(radii) {
+        fill_mirrored_rows(canvas, center, dy, -half_width..=half_width, paint);
+    }
+}
+
+/// Fills the pixels of an ellipse with the radii of `outer` which aren't
+/// covered by an ellipse with the radii of `inner`.
+fn fill_ring(
+    canvas: &mut impl Canvas,
+    center: Vec2<isize>,
+    outer: Vec2<isize>,
+    inner: Vec2<isize>,
+    paint: Paint,
+) {
+    let mut inner_rows = Rows::new(inner);
+
+    for (dy, half_width) in Rows::new(outer) {
+        let inner_half_width = match dy <= inner.y {
+            true => inner_rows.next().map(|(_, half_width)| half_width),
+            false => None,
+        };
+
+        match inner_half_width {
+            Some(inner_half_width) => {
+                let left = -half_width..=-inner_half_width - 1;
+                let right = inner_half_width + 1..=half_width;
+
+                fill_mirrored_rows(canvas, center, dy, left, paint);
+                fill_mirrored_rows(canvas, center, dy, right, paint);
+            }
+            None => {
+                fill_mirrored_rows(canvas, center, dy, -half_width..=half_width, paint);
+            }
+        }
+    }
+}
+
+/// Traces the boundary of an ellipse's quadrant, yielding the row offset
+/// from the center and the half-width of every row, starting at the top of
+/// the ellipse and ending at its middle row. Yields nothing if any of the
+/// radii is negative.
+///
+/// The quadrant gets traced column by column for as long as the boundary is
+/// closer to horizontal than to vertical, and row by row afterwards.
+///
+/// The equation of the ellipse is of the fourth degree in the radii, so it
+/// gets evaluated with 64-bit integers, which fit it for radii up to
+/// `i16::MAX`. Larger radii get clamped to that, which is as far as `isize`
+/// reaches on the device anyway.
+pub(super) struct Rows {
+    radii: Vec2<i64>,
+    x: i64,
+    y: i64,
+}
+
+impl Rows {
+    const MAX_RADIUS: i64 = i16::MAX as i64;
+
+    pub(super) fn new(radii: Vec2<isize>) -> Self {
+        let clamp = |radius: isize| (radius as i64).min(Self::MAX_RADIUS);
+        let radii = Vec2::new(clamp(radii.x), clamp(radii.y));
+
+        Self {
+            radii,
+            x: 0,
+            y: match radii.x < 0 {
+                true => -1,
+                false => radii.y,
+            },
+        }
+    }
+
+    /// Evaluates the implicit equation of the ellipse at `(x / 2, y / 2)`,
+    /// scaled by a factor of 4. The result is positive outside the ellipse.
+    fn equation(&self, x: i64, y: i64) -> i64 {
+        let Vec2 { x: a, y: b } = self.radii;
+
+        b * b * x * x + a * a * y * y - 4 * a * a * b * b
+    }
+}
+
+impl Iterator for Rows {
+    type Item = (isize, isize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Vec2 { x: a, y: b } = self.radii;
+        let y = self.y;
+
+        if y < 0 {
+            return None;
+        }
+
+        while b * b * self.x < a * a * y {
+            let x = self.x;
+            self.x += 1;
+
+            if self.equation(2 * x + 2, 2 * y - 1) > 0 {
+                self.y -= 1;
+                return Some((y as isize, x as isize));
+            }
+        }
+
+        while self.x < a && self.equation(2 * self.x + 1, 2 * y) <= 0 {
+            self.x += 1;
+        }
+
+        self.y -= 1;
+        Some((y as isize, self.x as isize))
+    }
+}
@@ -0,0 +1,162 @@
+use super::*;
+
+/// The maximum number of spans waiting to be filled at once.
+const STACK_SIZE: usize = 24;
+
+pub struct FloodFill {
+    seed: Vec2<isize>,
+    color: Color,
+}
+
+impl FloodFill {
+    #[inline]
+    pub fn new(seed: Vec2<isize>, color: Color) -> Self {
+        Self { seed, color }
+    }
+}
+
+impl FloodFill {
+    /// Fills the region of same-colored pixels connected horizontally and
+    /// vertically to the seed. Filling with [`Color::Toggle`] inverts the whole
+    /// region. Draws nothing if the seed lies outside of the canvas or already
+    /// has the fill color.
+    ///
+    /// The region gets filled one span at a time, without recursion, using a
+    /// fixed-size stack of spans waiting to be filled. Unlike the [`Draw`]
+    /// implementors, a flood fill needs to read the pixels of the canvas back.
+    ///
+    /// Once more than 24 spans wait to be filled at once, the seeds of the
+    /// spans that don't fit on the stack get dropped. After the stack runs
+    /// out, the rows around the filled area get rescanned for pixels of the
+    /// target color right above or below the pixels filled so far, and the
+    /// filling continues from there until a rescan finds nothing new.
+    ///
+    /// The filled pixels are told apart from the ones which already had the
+    /// fill color with a mask the size of a [`Framebuffer`], which takes up 504
+    /// bytes and covers the canvas from its origin. That's all of the screen,
+    /// but the pixels of a [`Translation`] lying outside of the mask never get
+    /// rescanned, so such a region may be left partially unfilled.
+    pub fn draw<C: ReadableCanvas>(&self, canvas: &mut C) {
+        let target = match canvas.get_pixel(self.seed.x, self.seed.y) {
+            Some(target) => target,
+            None => return,
+        };
+        let color = match (self.color, target) {
+            (Color::Toggle, Color::On) => Color::Off,
+            (Color::Toggle, _) => Color::On,
+            (color, _) => color,
+        };
+
+        if color == target {
+            return;
+        }
+
+        let is_target = |canvas: &C, x, y| canvas.get_pixel(x, y) == Some(target);
+        let mut stack = Stack::new();
+        let mut filled = Bounds::EMPTY;
+        let mut written = Framebuffer::new();
+        stack.push(self.seed);
+
+        loop {
+            while let Some(Vec2 { x, y }) = stack.pop() {
+                if !is_target(canvas, x, y) {
+                    continue;
+                }
+
+                let mut left = x;
+                while is_target(canvas, left - 1, y) {
+                    left -= 1;
+                }
+
+                let mut right = x;
+                while is_target(canvas, right + 1, y) {
+                    right += 1;
+                }
+
+                canvas.blit_span(left..=right, y, color);
+                written.blit_span(left..=right, y, Color::On);
+                filled = filled.union(Bounds::from_corners(
+                    Vec2::new(left, y),
+                    Vec2::new(right + 1, y + 1),
+                ));
+
+                for y in [y - 1, y + 1] {
+                    stack.push_spans(left..=right, y, |x| is_target(canvas, x, y));
+                }
+            }
+
+            if !stack.overflowed {
+                break;
+            }
+
+            stack.overflowed = false;
+
+            // every dropped seed lies right above or below a filled span
+            let borders_written = |x, y: isize| {
+                [y - 1, y + 1]
+                    .into_iter()
+                    .any(|y| written.get_pixel(x, y) == Some(Color::On))
+            };
+
+            for y in filled.position.y - 1..=filled.end().y {
+                stack.push_spans(filled.position.x..=filled.end().x - 1, y, |x| {
+                    is_target(canvas, x, y) && borders_written(x, y)
+                });
+            }
+        }
+    }
+}
+
+/// A stack of seeds of the spans waiting to be filled, which drops the seeds
+/// pushed onto it once it's full and remembers having done so.
+struct Stack {
+    seeds: [Vec2<isize>; STACK_SIZE],
+    len: usize,
+    overflowed: bool,
+}
+
+impl Stack {
+    fn new() -> Self {
+        Self {
+            seeds: [Vec2::new(0, 0); STACK_SIZE],
+            len: 0,
+            overflowed: false,
+        }
+    }
+
+    fn push(&mut self, seed: Vec2<isize>) {
+        match self.seeds.get_mut(self.len) {
+            Some(slot) => {
+                *slot = seed;
+                self.len += 1;
+            }
+            None => self.overflowed = true,
+        }
+    }
+
+    /// Pushes a seed for every span of pixels within `xs` on the row `y` that
+    /// satisfy the predicate.
+    fn push_spans(
+        &mut self,
+        xs: RangeInclusive<isize>,
+        y: isize,
+        mut predicate: impl FnMut(isize) -> bool,
+    ) {
+        let mut in_span = false;
+
+        for x in xs {
+            let matches = predicate(x);
+
+            if matches && !in_span {
+                self.push(Vec2::new(x, y));
+            }
+
+            in_span = matches;
+        }
+    }
+
+    fn pop(&mut self) -> Option<Vec2<isize>> {
+        self.len = self.len.checked_sub(1)?;
+        Some(self.seeds[self.len])
+    }
+}
@@ -0,0 +1,105 @@
+use super::*;
+
+pub struct Line {
+    start: Vec2<isize>,
+    end: Vec2<isize>,
+    thickness: isize,
+    paint: Paint,
+}
+
+impl Line {
+    #[inline]
+    pub fn new(start: Vec2<isize>, end: Vec2<isize>, paint: impl Into<Paint>) -> Self {
+        Self {
+            start,
+            end,
+            thickness: 1,
+            paint: paint.into(),
+        }
+    }
+}
+
+impl Line {
+    pub fn thickness(mut self, thickness: isize) -> Self {
+        self.thickness = thickness;
+        self
+    }
+}
+
+impl Draw for Line {
+    /// Draws a line using the Bresenham's line algorithm.
+    ///
+    /// Both endpoints are included in the line. Thick lines are drawn by
+    /// stretching every step of the line along its minor axis, so `thickness`
+    /// is measured vertically for mostly horizontal lines and horizontally for
+    /// mostly vertical lines. Values of `thickness` less than 1 draw nothing.
+    fn draw(&self, canvas: &mut impl Canvas) {
+        let Vec2 { x: x0, y: y0 } = self.start;
+        let Vec2 { x: x1, y: y1 } = self.end;
+        let offset = (self.thickness - 1) / 2;
+
+        if (x1 - x0).abs() >= (y1 - y0).abs() {
+            bresenham(x0, x1, y0, y1, |x, y| {
+                for dy in y - offset..y - offset + self.thickness {
+                    self.paint.blit_pixel(canvas, x, dy);
+                }
+            });
+        } else {
+            bresenham(y0, y1, x0, x1, |y, x| {
+                for dx in x - offset..x - offset + self.thickness {
+                    self.paint.blit_pixel(canvas, dx, y);
+                }
+            });
+        }
+    }
+
+    fn bounds(&self) -> Bounds {
+        let start = self.start.min(self.end);
+        let end = self.start.max(self.end) + Vec2::new(1, 1);
+        let offset = (self.thickness - 1) / 2;
+
+        // the line gets stretched along its minor axis only
+        let stretch = match (end.x - start.x) >= (end.y - start.y) {
+            true => Vec2::new(0, 1),
+            false => Vec2::new(1, 0),
+        };
+
+        match self.thickness < 1 {
+            true => Bounds::EMPTY,
+            false => Bounds::from_corners(
+                start - stretch * offset,
+                end + stretch * (self.thickness - 1 - offset),
+            ),
+        }
+    }
+}
+
+/// Steps along the major axis from `a0` to `a1`, calling `plot` with the
+/// coordinates of every point on the line. The distance along the major axis
+/// must not be shorter than the distance along the minor axis.
+fn bresenham(a0: isize, a1: isize, b0: isize, b1: isize, mut plot: impl FnMut(isize, isize)) {
+    let da = (a1 - a0).abs();
+    let db = (b1 - b0).abs();
+    let sa = if a0 < a1 { 1 } else { -1 };
+    let sb = if b0 < b1 { 1 } else { -1 };
+
+    let mut a = a0;
+    let mut b = b0;
+    let mut error = 2 * db - da;
+
+    loop {
+        plot(a, b);
+
+        if a == a1 {
+            break;
+        }
+
+        if error > 0 {
+            b += sb;
+            error -= 2 * da;
+        }
+
+        a += sa;
+        error += 2 * db;
+    }
+}
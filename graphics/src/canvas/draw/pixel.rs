@@ -16,4 +16,8 @@ impl Draw for Pixel {
     fn draw(&self, canvas: &mut impl Canvas) {
         canvas.blit_pixel(self.position.x, self.position.y, self.color);
     }
+
+    fn bounds(&self) -> Bounds {
+        Bounds::from_point(self.position)
+    }
 }
@@ -0,0 +1,227 @@
+use super::*;
+
+pub struct Polygon<const N: usize> {
+    vertices: [Vec2<isize>; N],
+    style: Style,
+}
+
+impl<const N: usize> Polygon<N> {
+    #[inline]
+    pub fn new(vertices: [Vec2<isize>; N], style: impl Into<Style>) -> Self {
+        Self {
+            vertices,
+            style: style.into(),
+        }
+    }
+}
+
+impl<const N: usize> Draw for Polygon<N> {
+    /// Draws a closed polygon, connecting every vertex with the next one and
+    /// the last vertex with the first one.
+    ///
+    /// The inside of the polygon is filled using the even-odd rule, one
+    /// scanline at a time, and includes the rows and columns of its vertices.
+    /// The outline consists of [`Line`]s as thick as the width of the outline,
+    /// centered on the edges of the polygon.
+    ///
+    /// Every pixel gets blitted at most once, even where the lines of the
+    /// outline meet or cover the inside.
+    fn draw(&self, canvas: &mut impl Canvas) {
+        let bounds = self.bounds();
+
+        for y in bounds.position.y..bounds.end().y {
+            let mut outline_spans = [(0, 0); N];
+            let outline_spans = match self.style.outline() {
+                Some((paint, width)) => {
+                    let spans = self.outline_spans(y, width, &mut outline_spans);
+
+                    for &(start, end) in spans.iter() {
+                        paint.blit_span(canvas, start..=end, y);
+                    }
+
+                    spans
+                }
+                None => &mut [],
+            };
+
+            if let Some(paint) = self.style.fill() {
+                let mut fill_spans = [(0, 0); N];
+
+                for &(start, end) in self.fill_spans(y, &mut fill_spans).iter() {
+                    // leave out the pixels already blitted by the outline
+                    let mut x = start;
+
+                    for &(outline_start, outline_end) in outline_spans.iter() {
+                        if outline_end < x {
+                            continue;
+                        }
+
+                        if outline_start > end {
+                            break;
+                        }
+
+                        if outline_start > x {
+                            paint.blit_span(canvas, x..=outline_start - 1, y);
+                        }
+
+                        x = outline_end + 1;
+                    }
+
+                    if x <= end {
+                        paint.blit_span(canvas, x..=end, y);
+                    }
+                }
+            }
+        }
+    }
+
+    fn bounds(&self) -> Bounds {
+        let fill = self
+            .vertices
+            .iter()
+            .map(|&vertex| Bounds::from_point(vertex))
+            .fold(Bounds::EMPTY, Bounds::union);
+
+        match self.style.outline() {
+            Some((paint, width)) => self
+                .edges()
+                .map(|(start, end)| Line::new(start, end, paint).thickness(width).bounds())
+                .fold(fill, Bounds::union),
+            None => fill,
+        }
+    }
+}
+
+impl<const N: usize> Polygon<N> {
+    fn edges(&self) -> impl Iterator<Item = (Vec2<isize>, Vec2<isize>)> + '_ {
+        self.vertices
+            .iter()
+            .zip(self.vertices.iter().cycle().skip(1))
+            .map(|(&start, &end)| (start, end))
+    }
+
+    /// Returns the spans of the inside of the polygon on the row `y`, sorted
+    /// and without any overlaps, stored in `spans`.
+    ///
+    /// The row gets sampled twice -- once with the edges including their top
+    /// endpoints and once with the edges including their bottom endpoints --
+    /// so that both the top and the bottom of the polygon get filled, and the
+    /// union of the spans found by both samples makes up the inside.
+    fn fill_spans<'a>(
+        &self,
+        y: isize,
+        spans: &'a mut [(isize, isize); N],
+    ) -> &'a mut [(isize, isize)] {
+        let mut span_count = 0;
+
+        for includes_top in [true, false] {
+            let mut crossings = [0; N];
+            let mut crossing_count = 0;
+
+            for (start, end) in self.edges() {
+                let (upper, lower) = match start.y <= end.y {
+                    true => (start, end),
+                    false => (end, start),
+                };
+                let crosses = match includes_top {
+                    true => upper.y <= y && y < lower.y,
+                    false => upper.y < y && y <= lower.y,
+                };
+
+                if crosses {
+                    crossings[crossing_count] = intersection(upper, lower, y);
+                    crossing_count += 1;
+                }
+            }
+
+            let crossings = &mut crossings[..crossing_count];
+            crossings.sort_unstable();
+
+            for pair in crossings.chunks_exact(2) {
+                spans[span_count] = (pair[0], pair[1]);
+                span_count += 1;
+            }
+        }
+
+        merge(&mut spans[..span_count])
+    }
+
+    /// Returns the spans of the outline of the polygon on the row `y`, sorted
+    /// and without any overlaps, stored in `spans`.
+    fn outline_spans<'a>(
+        &self,
+        y: isize,
+        width: isize,
+        spans: &'a mut [(isize, isize); N],
+    ) -> &'a mut [(isize, isize)] {
+        let mut span_count = 0;
+
+        for (start, end) in self.edges() {
+            let line = Line::new(start, end, Color::On).thickness(width);
+            let bounds = line.bounds();
+
+            if y < bounds.position.y || y >= bounds.end().y {
+                continue;
+            }
+
+            let mut row = Row { y, span: None };
+            line.draw(&mut row);
+
+            if let Some(span) = row.span {
+                spans[span_count] = span;
+                span_count += 1;
+            }
+        }
+
+        merge(&mut spans[..span_count])
+    }
+}
+
+/// Sorts the spans and merges the overlapping ones, returning the merged
+/// spans at the beginning of `spans`.
+fn merge(spans: &mut [(isize, isize)]) -> &mut [(isize, isize)] {
+    spans.sort_unstable();
+
+    let mut count = 0;
+
+    for i in 0..spans.len() {
+        let (start, end) = spans[i];
+
+        if count > 0 && start <= spans[count - 1].1 + 1 {
+            spans[count - 1].1 = spans[count - 1].1.max(end);
+        } else {
+            spans[count] = (start, end);
+            count += 1;
+        }
+    }
+
+    &mut spans[..count]
+}
+
+/// A canvas which keeps track of the leftmost and the rightmost pixel blitted
+/// onto the row `y` only. The pixels a [`Line`] blits onto any single row
+/// are always contiguous.
+struct Row {
+    y: isize,
+    span: Option<(isize, isize)>,
+}
+
+impl Canvas for Row {
+    fn blit_pixel(&mut self, x: isize, y: isize, _: Color) {
+        if y == self.y {
+            self.span = Some(match self.span {
+                Some((start, end)) => (start.min(x), end.max(x)),
+                None => (x, x),
+            });
+        }
+    }
+}
+
+/// Returns the X coordinate of the point where the edge crosses the row at
+/// `y`, rounded to the nearest pixel. The edge mustn't be horizontal.
+fn intersection(upper: Vec2<isize>, lower: Vec2<isize>, y: isize) -> isize {
+    let numerator = (y - upper.y) as i32 * (lower.x - upper.x) as i32;
+    let denominator = (lower.y - upper.y) as i32;
+
+    upper.x + (2 * numerator + denominator).div_euclid(2 * denominator) as isize
+}
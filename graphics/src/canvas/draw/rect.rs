@@ -0,0 +1,103 @@
+use super::*;
+use core::{mem, ops::Range};
+
+pub struct Rect {
+    position: Vec2<isize>,
+    size: Vec2<isize>,
+    style: Style,
+}
+
+impl Rect {
+    #[inline]
+    pub fn new(position: Vec2<isize>, size: Vec2<isize>, style: impl Into<Style>) -> Self {
+        Self {
+            position,
+            size,
+            style: style.into(),
+        }
+    }
+}
+
+impl Draw for Rect {
+    /// Draws an axis-aligned rectangle.
+    ///
+    /// Negative components of `size` are valid -- the rectangle extends to the
+    /// left or upwards from `position` instead. The outline is drawn inside the
+    /// bounds of the rectangle.
+    fn draw(&self, canvas: &mut impl Canvas) {
+        let xs = sorted(self.position.x..self.position.x + self.size.x);
+        let ys = sorted(self.position.y..self.position.y + self.size.y);
+        let fill = self.style.fill();
+        let (outline, width) = match self.style.outline() {
+            Some((paint, width)) => (Some(paint), width),
+            None => (None, 0),
+        };
+
+        // the bounds of the inside, clamped so that the sides of the outline
+        // never overlap
+        let top = (ys.start + width).min(ys.end);
+        let bottom = (ys.end - width).max(top);
+        let left = (xs.start + width).min(xs.end);
+        let right = (xs.end - width).max(left);
+
+        if let Some(paint) = outline {
+            let full_width = xs.end - xs.start;
+            let inner_height = bottom - top;
+
+            paint.fill_rect(
+                canvas,
+                Vec2::new(xs.start, ys.start),
+                Vec2::new(full_width, top - ys.start),
+            );
+            paint.fill_rect(
+                canvas,
+                Vec2::new(xs.start, bottom),
+                Vec2::new(full_width, ys.end - bottom),
+            );
+            paint.fill_rect(
+                canvas,
+                Vec2::new(xs.start, top),
+                Vec2::new(left - xs.start, inner_height),
+            );
+            paint.fill_rect(
+                canvas,
+                Vec2::new(right, top),
+                Vec2::new(xs.end - right, inner_height),
+            );
+        }
+
+        if let Some(paint) = fill {
+            paint.fill_rect(
+                canvas,
+                Vec2::new(left, top),
+                Vec2::new(right - left, bottom - top),
+            );
+        }
+    }
+
+    fn bounds(&self) -> Bounds {
+        let end = self.position + self.size;
+
+        Bounds::from_corners(self.position.min(end), self.position.max(end))
+    }
+}
+
+trait Sorted {
+    fn sorted(self) -> Self;
+}
+
+impl Sorted for Range<isize> {
+    fn sorted(self) -> Self {
+        let Self { mut start, mut end } = self;
+
+        if start > end {
+            mem::swap(&mut start, &mut end);
+        }
+
+        Self { start, end }
+    }
+}
+
+fn sorted<T: Sorted>(x: T) -> T {
+    x.sorted()
+}
@@ -0,0 +1,107 @@
+use super::ellipse::Rows;
+use super::*;
+use core::iter;
+
+pub struct RoundedRect {
+    position: Vec2<isize>,
+    size: Vec2<isize>,
+    radius: isize,
+    style: Style,
+}
+
+impl RoundedRect {
+    #[inline]
+    pub fn new(
+        position: Vec2<isize>,
+        size: Vec2<isize>,
+        radius: isize,
+        style: impl Into<Style>,
+    ) -> Self {
+        Self {
+            position,
+            size,
+            radius,
+            style: style.into(),
+        }
+    }
+}
+
+impl Draw for RoundedRect {
+    /// Draws an axis-aligned rectangle with its corners rounded off by the
+    /// quarters of a [`Circle`] with the given radius.
+    ///
+    /// Negative components of `size` are treated the same way as in [`Rect`].
+    /// The radius gets clamped so that the corners always fit inside the
+    /// rectangle. The outline is drawn inside the bounds of the rectangle and
+    /// its inner corners are rounded off by a radius smaller by the width of
+    /// the outline.
+    fn draw(&self, canvas: &mut impl Canvas) {
+        let left = self.position.x.min(self.position.x + self.size.x);
+        let top = self.position.y.min(self.position.y + self.size.y);
+        let width = self.size.x.abs();
+        let height = self.size.y.abs();
+
+        if width == 0 || height == 0 {
+            return;
+        }
+
+        let radius = self.radius.abs().min((width - 1) / 2).min((height - 1) / 2);
+        let fill = self.style.fill();
+        let (outline, outline_width) = match self.style.outline() {
+            Some((paint, width)) => (Some(paint), width),
+            None => (None, 0),
+        };
+
+        let has_inside = outline_width * 2 < width;
+        let mut inner_insets = insets((radius - outline_width).max(0));
+
+        for (i, inset) in (0..(height + 1) / 2).zip(insets(radius)) {
+            let outer = left + inset..=left + width - 1 - inset;
+            let inner = match has_inside && i >= outline_width {
+                true => inner_insets.next().map(|inset| {
+                    let inset = outline_width + inset;
+                    left + inset..=left + width - 1 - inset
+                }),
+                false => None,
+            };
+
+            for y in [top + i, top + height - 1 - i] {
+                match inner.clone() {
+                    Some(inner) => {
+                        if let Some(paint) = fill {
+                            paint.blit_span(canvas, inner.clone(), y);
+                        }
+
+                        if let Some(paint) = outline {
+                            paint.blit_span(canvas, *outer.start()..=*inner.start() - 1, y);
+                            paint.blit_span(canvas, *inner.end() + 1..=*outer.end(), y);
+                        }
+                    }
+                    None => {
+                        if let Some(paint) = outline {
+                            paint.blit_span(canvas, outer.clone(), y);
+                        }
+                    }
+                }
+
+                // the middle row of a rectangle with an odd height has no pair
+                if i * 2 + 1 == height {
+                    break;
+                }
+            }
+        }
+    }
+
+    fn bounds(&self) -> Bounds {
+        Rect::new(self.position, self.size, self.style).bounds()
+    }
+}
+
+/// Returns the distances between the sides of the rectangle and its rounded
+/// corners for every row, starting from the top of the rectangle.
+fn insets(radius: isize) -> impl Iterator<Item = isize> {
+    Rows::new(Vec2::new(radius, radius))
+        .take(radius as usize)
+        .map(move |(_, half_width)| radius - half_width)
+        .chain(iter::repeat(0))
+}
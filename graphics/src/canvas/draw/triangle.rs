@@ -0,0 +1,25 @@
+use super::*;
+
+pub struct Triangle {
+    polygon: Polygon<3>,
+}
+
+impl Triangle {
+    #[inline]
+    pub fn new(a: Vec2<isize>, b: Vec2<isize>, c: Vec2<isize>, style: impl Into<Style>) -> Self {
+        Self {
+            polygon: Polygon::new([a, b, c], style),
+        }
+    }
+}
+
+impl Draw for Triangle {
+    /// Draws a triangle the same way as a [`Polygon`] with three vertices.
+    fn draw(&self, canvas: &mut impl Canvas) {
+        self.polygon.draw(canvas);
+    }
+
+    fn bounds(&self) -> Bounds {
+        self.polygon.bounds()
+    }
+}
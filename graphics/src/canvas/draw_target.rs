@@ -0,0 +1,53 @@
+use super::*;
+use core::convert::Infallible;
+use embedded_graphics_core::{
+    draw_target::DrawTarget,
+    geometry::{OriginDimensions, Size},
+    pixelcolor::BinaryColor,
+    Pixel,
+};
+
+impl From<BinaryColor> for Color {
+    #[inline]
+    fn from(color: BinaryColor) -> Self {
+        match color {
+            BinaryColor::Off => Color::Off,
+            BinaryColor::On => Color::On,
+        }
+    }
+}
+
+impl<C> OriginDimensions for Viewport<'_, C> {
+    #[inline]
+    fn size(&self) -> Size {
+        Size::new(
+            self.bounds.size.x.max(0) as u32,
+            self.bounds.size.y.max(0) as u32,
+        )
+    }
+}
+
+/// Lets `embedded-graphics` draw onto any canvas through a viewport, which
+/// gives the canvas a size. Pixels outside of the viewport get clipped before
+/// their coordinates get narrowed down to an `isize`.
+impl<C: Canvas> DrawTarget for Viewport<'_, C> {
+    type Color = BinaryColor;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let size = self.size();
+
+        for Pixel(point, color) in pixels {
+            if (0..size.width as i32).contains(&point.x)
+                && (0..size.height as i32).contains(&point.y)
+            {
+                self.blit_pixel(point.x as isize, point.y as isize, color.into());
+            }
+        }
+
+        Ok(())
+    }
+}
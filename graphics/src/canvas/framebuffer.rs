@@ -0,0 +1,101 @@
+use super::{Canvas, ChunkSpan, Color, ReadableCanvas, Vec2};
+use core::ops::RangeInclusive;
+
+/// An in-memory canvas of the same size as the screen. Just like in the
+/// screen's memory, every byte stores a column of 8 pixels, the topmost one
+/// in the least significant bit.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Framebuffer {
+    chunks: [u8; Framebuffer::WIDTH * Framebuffer::HEIGHT / 8],
+}
+
+impl Framebuffer {
+    pub const WIDTH: usize = 84;
+    pub const HEIGHT: usize = 48;
+    const SIZE: Vec2<usize> = Vec2::new(Self::WIDTH, Self::HEIGHT);
+
+    /// Creates a framebuffer with all the pixels off.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            chunks: [0; Self::WIDTH * Self::HEIGHT / 8],
+        }
+    }
+
+    /// Turns all the pixels off.
+    #[inline]
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Returns the index of the chunk and the mask of the bit within the
+    /// chunk of the pixel at `(x, y)`.
+    fn locate(x: isize, y: isize) -> Option<(usize, u8)> {
+        let x = usize::try_from(x).ok().filter(|&x| x < Self::WIDTH)?;
+        let y = usize::try_from(y).ok().filter(|&y| y < Self::HEIGHT)?;
+
+        Some((y / 8 * Self::WIDTH + x, 1 << (y % 8)))
+    }
+}
+
+impl Default for Framebuffer {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Canvas for Framebuffer {
+    fn blit_pixel(&mut self, x: isize, y: isize, color: Color) {
+        if let Some((index, mask)) = Self::locate(x, y) {
+            blit_mask(&mut self.chunks[index], mask, color);
+        }
+    }
+
+    fn blit_span(&mut self, xs: RangeInclusive<isize>, y: isize, color: Color) {
+        if let Some(span) = ChunkSpan::of_span(xs, y, Self::SIZE) {
+            self.blit_chunk_span(span, color);
+        }
+    }
+
+    fn blit_chunk(&mut self, x: isize, y: isize, chunk: u8, color: Color) {
+        for span in ChunkSpan::of_chunk(x, y, chunk, Self::SIZE) {
+            self.blit_chunk_span(span, color);
+        }
+    }
+
+    fn fill_rect(&mut self, position: Vec2<isize>, size: Vec2<isize>, color: Color) {
+        for span in ChunkSpan::of_rect(position, size, Self::SIZE) {
+            self.blit_chunk_span(span, color);
+        }
+    }
+}
+
+impl Framebuffer {
+    fn blit_chunk_span(&mut self, span: ChunkSpan, color: Color) {
+        let offset = span.row * Self::WIDTH;
+
+        for chunk in &mut self.chunks[offset + span.columns.start..offset + span.columns.end] {
+            blit_mask(chunk, span.mask, color);
+        }
+    }
+}
+
+fn blit_mask(chunk: &mut u8, mask: u8, color: Color) {
+    match color {
+        Color::On => *chunk |= mask,
+        Color::Off => *chunk &= !mask,
+        Color::Toggle => *chunk ^= mask,
+    }
+}
+
+impl ReadableCanvas for Framebuffer {
+    fn get_pixel(&self, x: isize, y: isize) -> Option<Color> {
+        let (index, mask) = Self::locate(x, y)?;
+
+        match self.chunks[index] & mask {
+            0 => Some(Color::Off),
+            _ => Some(Color::On),
+        }
+    }
+}
@@ -0,0 +1,5 @@
+mod bounds;
+mod vec2;
+
+pub use self::bounds::*;
+pub use self::vec2::*;
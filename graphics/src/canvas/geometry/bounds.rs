@@ -0,0 +1,97 @@
+use super::Vec2;
+
+/// An axis-aligned rectangular area with its top-left corner at `position`.
+///
+/// Bounds with either component of `size` not positive are empty and contain
+/// no points. All the empty bounds are considered equal to each other.
+#[derive(Clone, Copy)]
+pub struct Bounds {
+    pub position: Vec2<isize>,
+    pub size: Vec2<isize>,
+}
+
+impl Bounds {
+    /// The bounds containing no points.
+    pub const EMPTY: Self = Self::new(Vec2::new(0, 0), Vec2::new(0, 0));
+
+    #[inline]
+    pub const fn new(position: Vec2<isize>, size: Vec2<isize>) -> Self {
+        Self { position, size }
+    }
+
+    /// Returns the bounds spanning from the `start` corner to the `end` corner,
+    /// with `start` included and `end` excluded.
+    #[inline]
+    pub fn from_corners(start: Vec2<isize>, end: Vec2<isize>) -> Self {
+        Self::new(start, end - start)
+    }
+
+    /// Returns the smallest bounds containing the given point.
+    #[inline]
+    pub fn from_point(point: Vec2<isize>) -> Self {
+        Self::new(point, Vec2::new(1, 1))
+    }
+
+    /// Returns the corner right below and to the right of the bottom-right
+    /// corner of the bounds.
+    #[inline]
+    pub fn end(&self) -> Vec2<isize> {
+        self.position + self.size
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.size.x <= 0 || self.size.y <= 0
+    }
+
+    #[inline]
+    pub fn contains(&self, point: Vec2<isize>) -> bool {
+        (self.position.x..self.end().x).contains(&point.x)
+            && (self.position.y..self.end().y).contains(&point.y)
+    }
+
+    /// Returns the bounds moved by `offset`.
+    #[inline]
+    pub fn translate(self, offset: Vec2<isize>) -> Self {
+        Self::new(self.position + offset, self.size)
+    }
+
+    /// Returns the bounds of the area shared by both bounds, which is empty if
+    /// they don't overlap.
+    pub fn intersect(self, other: Self) -> Self {
+        let bounds = Self::from_corners(
+            self.position.max(other.position),
+            self.end().min(other.end()),
+        );
+
+        match bounds.is_empty() {
+            true => Self::EMPTY,
+            false => bounds,
+        }
+    }
+
+    /// Returns the smallest bounds containing both bounds. Empty bounds don't
+    /// contribute to the result.
+    pub fn union(self, other: Self) -> Self {
+        match (self.is_empty(), other.is_empty()) {
+            (true, _) => other,
+            (_, true) => self,
+            _ => Self::from_corners(
+                self.position.min(other.position),
+                self.end().max(other.end()),
+            ),
+        }
+    }
+}
+
+impl PartialEq for Bounds {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.is_empty(), other.is_empty()) {
+            (true, true) => true,
+            (false, false) => self.position == other.position && self.size == other.size,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Bounds {}
@@ -0,0 +1,149 @@
+use core::{
+    num::TryFromIntError,
+    ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Vec2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Vec2 { x, y }
+    }
+}
+
+impl<T: Ord> Vec2<T> {
+    /// Returns the smaller of every pair of components.
+    #[inline]
+    pub fn min(self, other: Self) -> Self {
+        Self {
+            x: self.x.min(other.x),
+            y: self.y.min(other.y),
+        }
+    }
+
+    /// Returns the larger of every pair of components.
+    #[inline]
+    pub fn max(self, other: Self) -> Self {
+        Self {
+            x: self.x.max(other.x),
+            y: self.y.max(other.y),
+        }
+    }
+
+    /// Restricts every component to the range between the corresponding
+    /// components of `min` and `max`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any component of `min` is greater than the corresponding
+    /// component of `max`.
+    #[inline]
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        Self {
+            x: self.x.clamp(min.x, max.x),
+            y: self.y.clamp(min.y, max.y),
+        }
+    }
+}
+
+impl TryFrom<Vec2<isize>> for Vec2<usize> {
+    type Error = TryFromIntError;
+
+    fn try_from(Vec2 { x, y }: Vec2<isize>) -> Result<Self, Self::Error> {
+        let x = usize::try_from(x)?;
+        let y = usize::try_from(y)?;
+        Ok(Vec2 { x, y })
+    }
+}
+
+impl From<Vec2<usize>> for Vec2<isize> {
+    #[inline]
+    fn from(Vec2 { x, y }: Vec2<usize>) -> Self {
+        Self {
+            x: x as isize,
+            y: y as isize,
+        }
+    }
+}
+
+impl<T: AddAssign> AddAssign for Vec2<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: AddAssign> Add for Vec2<T> {
+    type Output = Vec2<T>;
+
+    #[inline]
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<T: SubAssign> SubAssign for Vec2<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<T: SubAssign> Sub for Vec2<T> {
+    type Output = Vec2<T>;
+
+    #[inline]
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vec2<T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl<T: MulAssign + Clone> MulAssign<T> for Vec2<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        self.x *= rhs.clone();
+        self.y *= rhs;
+    }
+}
+
+impl<T: MulAssign + Clone> Mul<T> for Vec2<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(mut self, rhs: T) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+
+impl<T: Div<Output = T> + Clone> Div<T> for Vec2<T> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: T) -> Self::Output {
+        Self {
+            x: self.x / rhs.clone(),
+            y: self.y / rhs,
+        }
+    }
+}
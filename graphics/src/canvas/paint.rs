@@ -0,0 +1,72 @@
+use super::{Canvas, Color, Pattern, Vec2};
+use core::ops::RangeInclusive;
+
+/// What a shape gets painted with -- either a single color or a [`Pattern`].
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Paint {
+    Solid(Color),
+    Pattern(Pattern),
+}
+
+impl Paint {
+    /// Returns the color of the paint at the given point of the canvas, or
+    /// `None` if the pixel there should be left untouched.
+    #[inline]
+    pub fn color_at(&self, x: isize, y: isize) -> Option<Color> {
+        match self {
+            Paint::Solid(color) => Some(*color),
+            Paint::Pattern(pattern) => pattern.color_at(x, y),
+        }
+    }
+
+    /// Blits the pixel at the given point with the color of the paint there.
+    #[inline]
+    pub fn blit_pixel(&self, canvas: &mut impl Canvas, x: isize, y: isize) {
+        if let Some(color) = self.color_at(x, y) {
+            canvas.blit_pixel(x, y, color);
+        }
+    }
+
+    /// Blits the pixels of the row `y` in the `xs` columns. Solid paints go
+    /// through [`Canvas::blit_span`], patterns get blitted pixel by pixel.
+    #[inline]
+    pub fn blit_span(&self, canvas: &mut impl Canvas, xs: RangeInclusive<isize>, y: isize) {
+        match self {
+            Paint::Solid(color) => canvas.blit_span(xs, y, *color),
+            Paint::Pattern(_) => {
+                for x in xs {
+                    self.blit_pixel(canvas, x, y);
+                }
+            }
+        }
+    }
+
+    /// Blits every pixel of the rectangle with its top-left corner at
+    /// `position`. Solid paints go through [`Canvas::fill_rect`], patterns
+    /// get blitted one row at a time.
+    #[inline]
+    pub fn fill_rect(&self, canvas: &mut impl Canvas, position: Vec2<isize>, size: Vec2<isize>) {
+        match self {
+            Paint::Solid(color) => canvas.fill_rect(position, size, *color),
+            Paint::Pattern(_) => {
+                for y in position.y..position.y + size.y {
+                    self.blit_span(canvas, position.x..=position.x + size.x - 1, y);
+                }
+            }
+        }
+    }
+}
+
+impl From<Color> for Paint {
+    #[inline]
+    fn from(color: Color) -> Self {
+        Paint::Solid(color)
+    }
+}
+
+impl From<Pattern> for Paint {
+    #[inline]
+    fn from(pattern: Pattern) -> Self {
+        Paint::Pattern(pattern)
+    }
+}
@@ -0,0 +1,103 @@
+use super::Color;
+
+/// The thresholds of a 4x4 ordered dithering matrix. The top-left 2x2 corner
+/// divided by 4 is the 2x2 matrix.
+const BAYER: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+/// An 8x8 pattern of pixels tiled across the whole canvas, used for painting
+/// shapes in shades of gray.
+///
+/// Each row of the pattern is a byte, with the most significant bit being the
+/// leftmost pixel. The set pixels get painted with the color of the pattern,
+/// and the rest get turned off, unless the pattern is transparent. The
+/// pattern is aligned to the origin of the canvas rather than to the shape, so
+/// neighboring shapes painted with the same pattern blend into each other.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Pattern {
+    rows: [u8; 8],
+    color: Color,
+    transparent: bool,
+}
+
+impl Pattern {
+    #[inline]
+    pub const fn new(rows: [u8; 8]) -> Self {
+        Self {
+            rows,
+            color: Color::On,
+            transparent: false,
+        }
+    }
+
+    /// Returns a pattern with every other pixel set, the same as
+    /// `Pattern::bayer_2x2(2)`.
+    #[inline]
+    pub const fn checkerboard() -> Self {
+        Self::bayer_2x2(2)
+    }
+
+    /// Returns an ordered dithering pattern with `level` out of every 4 pixels
+    /// set. Levels above 4 are treated as 4.
+    #[inline]
+    pub const fn bayer_2x2(level: u8) -> Self {
+        Self::bayer(2, level)
+    }
+
+    /// Returns an ordered dithering pattern with `level` out of every 16
+    /// pixels set. Levels above 16 are treated as 16.
+    #[inline]
+    pub const fn bayer_4x4(level: u8) -> Self {
+        Self::bayer(4, level)
+    }
+
+    const fn bayer(size: usize, level: u8) -> Self {
+        let scale = (16 / (size * size)) as u8;
+        let mut rows = [0; 8];
+        let mut y = 0;
+
+        while y < 8 {
+            let mut x = 0;
+
+            while x < 8 {
+                if BAYER[y % size][x % size] / scale < level {
+                    rows[y] |= 0x80 >> x;
+                }
+
+                x += 1;
+            }
+
+            y += 1;
+        }
+
+        Self::new(rows)
+    }
+
+    /// Sets the color of the set pixels of the pattern. [`Color::On`] by
+    /// default.
+    #[inline]
+    pub const fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Leaves the pixels under the unset pixels of the pattern untouched
+    /// instead of turning them off.
+    #[inline]
+    pub const fn transparent(mut self) -> Self {
+        self.transparent = true;
+        self
+    }
+
+    /// Returns the color of the pattern at the given point of the canvas, or
+    /// `None` if the pixel there should be left untouched.
+    #[inline]
+    pub fn color_at(&self, x: isize, y: isize) -> Option<Color> {
+        let is_set = self.rows[(y & 7) as usize] & (0x80 >> (x & 7)) != 0;
+
+        match (is_set, self.transparent) {
+            (true, _) => Some(self.color),
+            (false, false) => Some(Color::Off),
+            (false, true) => None,
+        }
+    }
+}
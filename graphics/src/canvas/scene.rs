@@ -0,0 +1,164 @@
+use super::*;
+
+/// A drawable placed in a [`Scene`].
+///
+/// Layers with a higher `z` get drawn over the ones with a lower `z`, and
+/// layers with the same `z` get drawn in the order they appear in the scene.
+/// The `offset` moves the drawable relative to the anchor of the scene.
+pub struct Layer<D> {
+    drawable: D,
+    z: i8,
+    visible: bool,
+    offset: Vec2<isize>,
+}
+
+impl<D> Layer<D> {
+    #[inline]
+    pub const fn new(drawable: D) -> Self {
+        Self {
+            drawable,
+            z: 0,
+            visible: true,
+            offset: Vec2::new(0, 0),
+        }
+    }
+}
+
+impl<D> Layer<D> {
+    pub const fn z(mut self, z: i8) -> Self {
+        self.z = z;
+        self
+    }
+
+    pub const fn offset(mut self, offset: Vec2<isize>) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub const fn hidden(mut self) -> Self {
+        self.visible = false;
+        self
+    }
+}
+
+impl<D> Layer<D> {
+    #[inline]
+    pub fn drawable(&self) -> &D {
+        &self.drawable
+    }
+
+    #[inline]
+    pub fn drawable_mut(&mut self) -> &mut D {
+        &mut self.drawable
+    }
+
+    #[inline]
+    pub fn set_z(&mut self, z: i8) {
+        self.z = z;
+    }
+
+    #[inline]
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    #[inline]
+    pub fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    #[inline]
+    pub fn set_offset(&mut self, offset: Vec2<isize>) {
+        self.offset = offset;
+    }
+
+    /// Moves the layer by `delta` from where it currently is.
+    #[inline]
+    pub fn move_by(&mut self, delta: Vec2<isize>) {
+        self.offset += delta;
+    }
+}
+
+/// A fixed number of layers drawn relative to a common anchor, so that they
+/// can be moved together by moving the anchor.
+///
+/// Every layer holds the same type of drawable. Scenes made of different
+/// kinds of drawables can use an enum of them implementing [`Draw`].
+pub struct Scene<D, const N: usize> {
+    anchor: Vec2<isize>,
+    layers: [Layer<D>; N],
+}
+
+impl<D, const N: usize> Scene<D, N> {
+    #[inline]
+    pub const fn new(anchor: Vec2<isize>, layers: [Layer<D>; N]) -> Self {
+        Self { anchor, layers }
+    }
+
+    #[inline]
+    pub fn anchor(&self) -> Vec2<isize> {
+        self.anchor
+    }
+
+    #[inline]
+    pub fn set_anchor(&mut self, anchor: Vec2<isize>) {
+        self.anchor = anchor;
+    }
+
+    /// Returns the layer at `index` in the order the layers were given in.
+    #[inline]
+    pub fn layer(&self, index: usize) -> &Layer<D> {
+        &self.layers[index]
+    }
+
+    /// Returns the layer at `index` in the order the layers were given in.
+    #[inline]
+    pub fn layer_mut(&mut self, index: usize) -> &mut Layer<D> {
+        &mut self.layers[index]
+    }
+
+    #[inline]
+    pub fn layers_mut(&mut self) -> impl Iterator<Item = &mut Layer<D>> {
+        self.layers.iter_mut()
+    }
+}
+
+impl<D: Draw, const N: usize> Draw for Scene<D, N> {
+    /// Draws the visible layers from the lowest to the highest `z`.
+    ///
+    /// The layers get picked one by one instead of being sorted, as there's no
+    /// room to sort them into. That takes quadratic time, which is fine for
+    /// scenes with a handful of layers.
+    fn draw(&self, canvas: &mut impl Canvas) {
+        let mut previous: Option<(i8, usize)> = None;
+
+        while let Some((z, index)) = self
+            .layers
+            .iter()
+            .enumerate()
+            .filter(|(_, layer)| layer.visible)
+            .map(|(index, layer)| (layer.z, index))
+            .filter(|&key| Some(key) > previous)
+            .min()
+        {
+            let layer = &self.layers[index];
+            let canvas = &mut canvas.translation(self.anchor + layer.offset);
+            layer.drawable.draw(canvas);
+
+            previous = Some((z, index));
+        }
+    }
+
+    fn bounds(&self) -> Bounds {
+        self.layers
+            .iter()
+            .filter(|layer| layer.visible)
+            .map(|layer| {
+                layer
+                    .drawable
+                    .bounds()
+                    .translate(self.anchor + layer.offset)
+            })
+            .fold(Bounds::EMPTY, Bounds::union)
+    }
+}
@@ -0,0 +1,54 @@
+use super::{Color, Paint, Pattern};
+
+/// Describes which parts of a shape get painted and with what paints.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// Paints the whole shape with a single paint.
+    Filled(Paint),
+    /// Paints only the outline of the shape, `width` pixels thick, leaving
+    /// the inside untouched.
+    Outlined { paint: Paint, width: isize },
+    /// Paints the outline and the inside of the shape with separate paints.
+    FilledOutlined {
+        fill: Paint,
+        outline: Paint,
+        width: isize,
+    },
+}
+
+impl Style {
+    /// Returns the paint of the inside of the shape, if it gets painted.
+    #[inline]
+    pub fn fill(&self) -> Option<Paint> {
+        match *self {
+            Style::Filled(paint) => Some(paint),
+            Style::Outlined { .. } => None,
+            Style::FilledOutlined { fill, .. } => Some(fill),
+        }
+    }
+
+    /// Returns the paint and the width of the outline of the shape, if it
+    /// gets painted. Negative widths are treated as 0.
+    #[inline]
+    pub fn outline(&self) -> Option<(Paint, isize)> {
+        match *self {
+            Style::Filled(_) => None,
+            Style::Outlined { paint, width } => Some((paint, width.max(0))),
+            Style::FilledOutlined { outline, width, .. } => Some((outline, width.max(0))),
+        }
+    }
+}
+
+impl From<Color> for Style {
+    #[inline]
+    fn from(color: Color) -> Self {
+        Style::Filled(color.into())
+    }
+}
+
+impl From<Pattern> for Style {
+    #[inline]
+    fn from(pattern: Pattern) -> Self {
+        Style::Filled(pattern.into())
+    }
+}
@@ -0,0 +1,147 @@
+use super::*;
+use stockbook::{stamp, Stamp};
+
+static REGULAR_SHEET: Stamp = stamp!("assets/fonts/5x7.png");
+static TINY_SHEET: Stamp = stamp!("assets/fonts/3x5.png");
+
+/// A fixed-width bitmap font covering the printable ASCII characters.
+///
+/// The glyphs are read from a sheet in which they are laid out in rows, left
+/// to right, starting with the space character. Characters without a glyph
+/// get drawn as `?`.
+#[derive(Clone, Copy)]
+pub struct Font {
+    sheet: &'static Stamp,
+    glyph_size: Vec2<usize>,
+}
+
+impl Font {
+    pub const fn new(sheet: &'static Stamp, glyph_size: Vec2<usize>) -> Self {
+        Self { sheet, glyph_size }
+    }
+
+    /// Returns a 5x7 font fitting 14 characters in a row of the screen.
+    pub fn regular() -> Self {
+        Self::new(&REGULAR_SHEET, Vec2::new(5, 7))
+    }
+
+    /// Returns a 3x5 font fitting 21 characters in a row of the screen.
+    pub fn tiny() -> Self {
+        Self::new(&TINY_SHEET, Vec2::new(3, 5))
+    }
+}
+
+impl Font {
+    const FIRST: char = ' ';
+    const LAST: char = '~';
+    const FALLBACK: char = '?';
+
+    pub fn glyph_size(&self) -> Vec2<usize> {
+        self.glyph_size
+    }
+
+    /// Returns the distance between the origins of two neighboring glyphs,
+    /// leaving a single column of spacing between them.
+    pub fn advance(&self) -> isize {
+        self.glyph_size.x as isize + 1
+    }
+
+    /// Returns the distance between the origins of two neighboring lines,
+    /// leaving a single row of spacing between them.
+    pub fn line_height(&self) -> isize {
+        self.glyph_size.y as isize + 1
+    }
+
+    /// Draws the glyph of `c` with its top-left corner at `position`. Only the
+    /// pixels of the glyph itself get drawn, leaving the background untouched.
+    pub fn draw_glyph(
+        &self,
+        canvas: &mut impl Canvas,
+        c: char,
+        position: Vec2<isize>,
+        color: Color,
+    ) {
+        let c = match c {
+            Self::FIRST..=Self::LAST => c,
+            _ => Self::FALLBACK,
+        };
+        let index = c as usize - Self::FIRST as usize;
+        let columns = self.sheet.width() / self.glyph_size.x;
+        let origin = Vec2::new(
+            index % columns * self.glyph_size.x,
+            index / columns * self.glyph_size.y,
+        );
+
+        let width = self.glyph_size.x;
+        let height = self.glyph_size.y;
+        let chunks = (0..height).step_by(8).flat_map(|top| {
+            (0..width).map(move |u| {
+                (top..height.min(top + 8)).fold(0, |chunk, v| {
+                    match self.sheet.get_pixel(origin.x + u, origin.y + v) {
+                        Some(stockbook::Color::Black) => chunk | 1 << (v - top),
+                        _ => chunk,
+                    }
+                })
+            })
+        });
+
+        canvas.blit_bitmap(position, width, chunks, color);
+    }
+}
+
+pub struct Text<'a> {
+    text: &'a str,
+    position: Vec2<isize>,
+    font: Font,
+    color: Color,
+}
+
+impl<'a> Text<'a> {
+    #[inline]
+    pub fn new(text: &'a str, position: Vec2<isize>, font: Font, color: Color) -> Self {
+        Self {
+            text,
+            position,
+            font,
+            color,
+        }
+    }
+}
+
+impl Draw for Text<'_> {
+    /// Draws the text one glyph after another, with the top-left corner of the
+    /// first glyph at `position`. Every `\n` character moves the following
+    /// glyphs to the beginning of the next line.
+    fn draw(&self, canvas: &mut impl Canvas) {
+        let mut cursor = self.position;
+
+        for c in self.text.chars() {
+            if c == '\n' {
+                cursor = Vec2::new(self.position.x, cursor.y + self.font.line_height());
+                continue;
+            }
+
+            self.font.draw_glyph(canvas, c, cursor, self.color);
+            cursor.x += self.font.advance();
+        }
+    }
+
+    /// Returns the bounds of the glyphs of the text, without the spacing after
+    /// the last glyph of every line.
+    fn bounds(&self) -> Bounds {
+        let glyph_size = Vec2::from(self.font.glyph_size());
+
+        self.text
+            .split('\n')
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(i, line)| {
+                let position = self.position + Vec2::new(0, i as isize * self.font.line_height());
+                let width =
+                    (line.chars().count() as isize - 1) * self.font.advance() + glyph_size.x;
+
+                Bounds::new(position, Vec2::new(width, glyph_size.y))
+            })
+            .fold(Bounds::EMPTY, Bounds::union)
+    }
+}
@@ -0,0 +1,46 @@
+use super::*;
+
+/// A canvas drawing onto another canvas with its origin moved by `offset`.
+///
+/// Unlike a [`Viewport`], a translation doesn't clip anything.
+pub struct Translation<'a, C> {
+    canvas: &'a mut C,
+    offset: Vec2<isize>,
+}
+
+impl<'a, C> Translation<'a, C> {
+    #[inline]
+    pub fn new(canvas: &'a mut C, offset: Vec2<isize>) -> Self {
+        Self { canvas, offset }
+    }
+}
+
+impl<C: Canvas> Canvas for Translation<'_, C> {
+    fn blit_pixel(&mut self, x: isize, y: isize, color: Color) {
+        self.canvas
+            .blit_pixel(self.offset.x + x, self.offset.y + y, color);
+    }
+
+    fn blit_span(&mut self, xs: RangeInclusive<isize>, y: isize, color: Color) {
+        self.canvas.blit_span(
+            self.offset.x + xs.start()..=self.offset.x + xs.end(),
+            self.offset.y + y,
+            color,
+        );
+    }
+
+    fn blit_chunk(&mut self, x: isize, y: isize, chunk: u8, color: Color) {
+        self.canvas
+            .blit_chunk(self.offset.x + x, self.offset.y + y, chunk, color);
+    }
+
+    fn fill_rect(&mut self, position: Vec2<isize>, size: Vec2<isize>, color: Color) {
+        self.canvas.fill_rect(self.offset + position, size, color);
+    }
+}
+
+impl<C: ReadableCanvas> ReadableCanvas for Translation<'_, C> {
+    fn get_pixel(&self, x: isize, y: isize) -> Option<Color> {
+        self.canvas.get_pixel(self.offset.x + x, self.offset.y + y)
+    }
+}
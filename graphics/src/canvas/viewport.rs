@@ -0,0 +1,79 @@
+use super::*;
+
+/// A canvas drawing onto a rectangular area of another canvas.
+///
+/// The coordinates of the viewport are relative to the top-left corner of
+/// the area and everything drawn outside of the area gets clipped.
+pub struct Viewport<'a, C> {
+    canvas: &'a mut C,
+    pub(super) bounds: Bounds,
+}
+
+impl<'a, C> Viewport<'a, C> {
+    #[inline]
+    pub fn new(canvas: &'a mut C, bounds: Bounds) -> Self {
+        Self { canvas, bounds }
+    }
+}
+
+impl<C> Viewport<'_, C> {
+    /// Returns the area of the viewport in its own coordinates.
+    fn local_bounds(&self) -> Bounds {
+        Bounds::new(Vec2::new(0, 0), self.bounds.size)
+    }
+}
+
+impl<C: Canvas> Canvas for Viewport<'_, C> {
+    fn blit_pixel(&mut self, x: isize, y: isize, color: Color) {
+        if self.local_bounds().contains(Vec2::new(x, y)) {
+            let Vec2 { x, y } = self.bounds.position + Vec2::new(x, y);
+            self.canvas.blit_pixel(x, y, color);
+        }
+    }
+
+    fn blit_span(&mut self, xs: RangeInclusive<isize>, y: isize, color: Color) {
+        let span = Bounds::from_corners(Vec2::new(*xs.start(), y), Vec2::new(*xs.end() + 1, y + 1));
+        let span = span.intersect(self.local_bounds());
+
+        if !span.is_empty() {
+            let Bounds { position, size } = span.translate(self.bounds.position);
+            self.canvas
+                .blit_span(position.x..=position.x + size.x - 1, position.y, color);
+        }
+    }
+
+    fn blit_chunk(&mut self, x: isize, y: isize, mut chunk: u8, color: Color) {
+        let bounds = self.local_bounds();
+
+        for i in 0..8 {
+            if !bounds.contains(Vec2::new(x, y + i)) {
+                chunk &= !(1 << i);
+            }
+        }
+
+        if chunk != 0 {
+            let Vec2 { x, y } = self.bounds.position + Vec2::new(x, y);
+            self.canvas.blit_chunk(x, y, chunk, color);
+        }
+    }
+
+    fn fill_rect(&mut self, position: Vec2<isize>, size: Vec2<isize>, color: Color) {
+        let rect = Bounds::new(position, size).intersect(self.local_bounds());
+
+        if !rect.is_empty() {
+            let Bounds { position, size } = rect.translate(self.bounds.position);
+            self.canvas.fill_rect(position, size, color);
+        }
+    }
+}
+
+impl<C: ReadableCanvas> ReadableCanvas for Viewport<'_, C> {
+    fn get_pixel(&self, x: isize, y: isize) -> Option<Color> {
+        if self.local_bounds().contains(Vec2::new(x, y)) {
+            let Vec2 { x, y } = self.bounds.position + Vec2::new(x, y);
+            self.canvas.get_pixel(x, y)
+        } else {
+            None
+        }
+    }
+}
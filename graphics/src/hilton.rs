@@ -0,0 +1,132 @@
+use crate::canvas::*;
+
+const POSITION: Vec2<isize> = Vec2::new(27, 4);
+
+pub const HEAD: usize = 0;
+pub const EYES: usize = 1;
+pub const NOSE: usize = 2;
+pub const RIGHT_EAR: usize = 3;
+pub const LEFT_EAR: usize = 4;
+pub const STRAND: usize = 5;
+pub const LEFT_WHISKERS: usize = 6;
+pub const RIGHT_WHISKERS: usize = 7;
+pub const TORSO: usize = 8;
+pub const TAIL: usize = 9;
+
+/// A part of Hilton, drawn relative to the top-left corner of its layer.
+pub enum Part {
+    Head,
+    Eyes { look_direction: Vec2<isize> },
+    Nose,
+    Bitmap(Bitmap),
+}
+
+impl Draw for Part {
+    fn draw(&self, canvas: &mut impl Canvas) {
+        match self {
+            Part::Head => head().draw(canvas),
+            Part::Eyes { look_direction } => {
+                let (eyes, pupils) = eyes(*look_direction);
+                eyes.draw(canvas);
+                pupils.draw(canvas);
+            }
+            Part::Nose => nose().draw(canvas),
+            Part::Bitmap(bitmap) => bitmap.draw(canvas),
+        }
+    }
+
+    fn bounds(&self) -> Bounds {
+        match self {
+            Part::Head => head().bounds(),
+            Part::Eyes { look_direction } => {
+                let (eyes, pupils) = eyes(*look_direction);
+                eyes.bounds().union(pupils.bounds())
+            }
+            Part::Nose => nose().bounds(),
+            Part::Bitmap(bitmap) => bitmap.bounds(),
+        }
+    }
+}
+
+/// Returns the scene of Hilton in his default pose. The layers are indexed by
+/// the constants named after the parts, like [`EYES`] or [`TAIL`].
+pub fn scene() -> Scene<Part, 10> {
+    let origin = Vec2::new(0, 0);
+
+    Scene::new(
+        POSITION,
+        [
+            Layer::new(Part::Head),
+            Layer::new(Part::Eyes {
+                look_direction: Vec2::new(0, 0),
+            })
+            .z(1),
+            Layer::new(Part::Nose).z(1),
+            Layer::new(Part::Bitmap(Bitmap::ear(origin)))
+                .z(1)
+                .offset(Vec2::new(21, 0)),
+            Layer::new(Part::Bitmap(Bitmap::ear(origin).flip_h())).z(1),
+            Layer::new(Part::Bitmap(Bitmap::strand(origin)))
+                .z(1)
+                .offset(Vec2::new(15, 0)),
+            Layer::new(Part::Bitmap(Bitmap::whiskers(origin)))
+                .z(1)
+                .offset(Vec2::new(1, 22)),
+            Layer::new(Part::Bitmap(Bitmap::whiskers(origin).flip_h()))
+                .z(1)
+                .offset(Vec2::new(23, 22)),
+            Layer::new(Part::Bitmap(Bitmap::torso(origin)))
+                .z(1)
+                .offset(Vec2::new(7, 29)),
+            Layer::new(Part::Bitmap(Bitmap::tail(origin)))
+                .z(1)
+                .offset(Vec2::new(23, 32)),
+        ],
+    )
+}
+
+pub fn draw(canvas: &mut impl Canvas) {
+    scene().draw(canvas);
+}
+
+fn head() -> Circle {
+    Circle::new(Vec2::new(15, 16), 12, Color::On)
+}
+
+fn eyes(look_direction: Vec2<isize>) -> ([Circle; 2], [Rect; 2]) {
+    const EYE_POSITIONS: [Vec2<isize>; 2] = [Vec2::new(10, 20), Vec2::new(20, 20)];
+    const PUPIL_ORIGINS: [Vec2<isize>; 2] = [Vec2::new(10, 19), Vec2::new(19, 19)];
+    let pupil_offsets = pupils_offsets(look_direction);
+
+    let eyes = EYE_POSITIONS.map(|position| Circle::new(position, 3, Color::Off));
+    let pupils = [0, 1].map(|i| {
+        let position = PUPIL_ORIGINS[i] + pupil_offsets[i];
+        Rect::new(position, Vec2::new(2, 3), Color::On)
+    });
+
+    (eyes, pupils)
+}
+
+fn pupils_offsets(look_direction: Vec2<isize>) -> [Vec2<isize>; 2] {
+    let [left_x, right_x] = match look_direction.x {
+        0 => [0, 0],
+        x if x > 0 => [x - 1, x],
+        x if x < 0 => [x, x + 1],
+        _ => unreachable!(),
+    };
+
+    [
+        Vec2::new(left_x, look_direction.y),
+        Vec2::new(right_x, look_direction.y),
+    ]
+}
+
+fn nose() -> [Pixel; 4] {
+    [
+        Vec2::new(14, 24),
+        Vec2::new(15, 24),
+        Vec2::new(16, 24),
+        Vec2::new(15, 25),
+    ]
+    .map(|position| Pixel::new(position, Color::Off))
+}
@@ -0,0 +1,4 @@
+#![no_std]
+
+pub mod canvas;
+pub mod hilton;
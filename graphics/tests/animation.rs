@@ -0,0 +1,146 @@
+//! Checks how [`Animation::advance`] moves through the frames.
+
+use hilton_graphics::canvas::{
+    Animation, Color, Draw, Frame, Framebuffer, Pixel, ReadableCanvas, Vec2,
+};
+
+/// Returns an animation whose frames light up the pixel in the column of
+/// their index and last as long as given.
+fn animation<const N: usize>(durations: [u16; N]) -> Animation<Pixel, N> {
+    let mut index = 0;
+
+    Animation::new(durations.map(|duration| {
+        index += 1;
+        Frame::new(Pixel::new(Vec2::new(index - 1, 0), Color::On), duration)
+    }))
+}
+
+/// Advances the animation by every step in turn and returns the frames it
+/// ends up on after each of them.
+fn frames<const N: usize>(animation: &mut Animation<Pixel, N>, steps: &[u16]) -> Vec<usize> {
+    steps
+        .iter()
+        .map(|&dt| {
+            animation.advance(dt);
+            animation.current()
+        })
+        .collect()
+}
+
+#[test]
+fn frames_last_their_duration() {
+    let mut animation = animation([10, 20, 30]);
+
+    assert_eq!(animation.current(), 0);
+    assert_eq!(frames(&mut animation, &[0, 9, 1, 19, 1]), [0, 0, 1, 1, 2]);
+    assert_eq!(frames(&mut animation, &[29, 1]), [2, 0]);
+}
+
+#[test]
+fn long_steps_skip_frames() {
+    let mut animation = animation([10, 20, 30, 40]);
+
+    // the time left over from the skipped frames carries over to the next one
+    assert_eq!(frames(&mut animation, &[35, 24, 1]), [2, 2, 3]);
+    assert_eq!(frames(&mut animation, &[39, 1]), [3, 0]);
+}
+
+#[test]
+fn animations_wrap_around() {
+    let mut looped = animation([10, 20, 30]);
+    let mut direct = animation([10, 20, 30]);
+
+    // whole loops of the animation end up on the same frame
+    for dt in [60, 125, 6000, u16::MAX] {
+        looped.reset();
+        direct.reset();
+
+        looped.advance(dt);
+        direct.advance(dt % 60);
+
+        assert_eq!(looped.current(), direct.current(), "{dt} ms");
+        assert_eq!(
+            frames(&mut looped, &[0, 1, 9, 20, 30]),
+            frames(&mut direct, &[0, 1, 9, 20, 30]),
+            "{dt} ms",
+        );
+    }
+
+    // the elapsed time of the current frame doesn't overflow either
+    let mut animation = animation([u16::MAX, u16::MAX]);
+    assert_eq!(
+        frames(&mut animation, &[u16::MAX - 1, u16::MAX, 1, u16::MAX]),
+        [0, 1, 0, 1],
+    );
+}
+
+#[test]
+fn zero_length_frames_are_skipped() {
+    let mut gaps = animation([0, 10, 0, 0, 20, 0]);
+
+    assert_eq!(frames(&mut gaps, &[0, 9, 1, 20]), [1, 1, 4, 1]);
+
+    let mut single = animation([10, 0]);
+
+    for _ in 0..5 {
+        assert_eq!(frames(&mut single, &[5, 5]), [0, 0]);
+    }
+}
+
+#[test]
+fn animations_without_time_never_move() {
+    let mut empty = animation([]);
+    let mut frozen = animation([0, 0, 0]);
+
+    for dt in [0, 1, u16::MAX] {
+        empty.advance(dt);
+        frozen.advance(dt);
+
+        assert_eq!(empty.current(), 0);
+        assert_eq!(frozen.current(), 0);
+    }
+
+    let mut framebuffer = Framebuffer::new();
+    empty.draw(&mut framebuffer);
+
+    assert!(framebuffer == Framebuffer::new());
+    assert!(empty.bounds().is_empty());
+}
+
+#[test]
+fn reset_goes_back_to_the_beginning() {
+    let mut animation = animation([10, 20]);
+
+    animation.advance(15);
+    animation.reset();
+
+    assert_eq!(animation.current(), 0);
+    assert_eq!(frames(&mut animation, &[9, 1]), [0, 1]);
+}
+
+#[test]
+fn only_the_current_frame_gets_drawn() {
+    let mut animation = animation([10, 10, 10]);
+
+    for frame in [0, 1, 2, 0] {
+        let mut framebuffer = Framebuffer::new();
+        animation.draw(&mut framebuffer);
+
+        for x in 0..3 {
+            let expected = match x as usize == frame {
+                true => Color::On,
+                false => Color::Off,
+            };
+
+            assert!(
+                framebuffer.get_pixel(x, 0) == Some(expected),
+                "frame {frame}, column {x}",
+            );
+        }
+
+        assert!(animation.bounds().contains(Vec2::new(frame as isize, 0)));
+        assert!(animation.bounds().size == Vec2::new(1, 1));
+
+        animation.advance(10);
+    }
+}
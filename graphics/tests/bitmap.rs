@@ -0,0 +1,106 @@
+//! Checks that the compressed bitmaps decode to the same pixels as the images
+//! they were compressed from, however they get drawn.
+
+use hilton_graphics::canvas::{Bitmap, Canvas, Color, Draw, Framebuffer, Vec2};
+use stockbook::{stamp, Stamp};
+
+static EAR: Stamp = stamp!("assets/ear.png");
+static STRAND: Stamp = stamp!("assets/strand.png");
+static WHISKERS: Stamp = stamp!("assets/whiskers.png");
+static TORSO: Stamp = stamp!("assets/torso.png");
+static TAIL: Stamp = stamp!("assets/tail.png");
+
+type Constructor = fn(Vec2<isize>) -> Bitmap;
+type Transformation = fn(Bitmap) -> Bitmap;
+
+/// Returns every compressed bitmap along with the raw image it was compressed
+/// from.
+fn bitmaps() -> [(&'static str, Constructor, &'static Stamp); 5] {
+    [
+        ("ear", Bitmap::ear, &EAR),
+        ("strand", Bitmap::strand, &STRAND),
+        ("whiskers", Bitmap::whiskers, &WHISKERS),
+        // the only bitmap stored as runs
+        ("torso", Bitmap::torso, &TORSO),
+        ("tail", Bitmap::tail, &TAIL),
+    ]
+}
+
+/// Returns the transformations the bitmaps get checked with, which read the
+/// pixels of the bitmaps in different orders.
+fn transformations() -> [(&'static str, Transformation); 10] {
+    [
+        ("as is", |bitmap| bitmap),
+        ("flipped horizontally", Bitmap::flip_h),
+        ("flipped vertically", Bitmap::flip_v),
+        ("rotated by 90 degrees", Bitmap::rotate_90),
+        ("rotated by 180 degrees", Bitmap::rotate_180),
+        ("rotated by 270 degrees and flipped", |bitmap| {
+            bitmap.rotate_270().flip_h()
+        }),
+        ("scaled", |bitmap| bitmap.scale(3)),
+        ("opaque", |bitmap| bitmap.rotate_90().opaque()),
+        ("toggled", |bitmap| bitmap.color(Color::Toggle).flip_v()),
+        ("turned off", |bitmap| bitmap.opaque().color(Color::Off)),
+    ]
+}
+
+/// Returns a framebuffer with some of its pixels on, so that it shows which
+/// pixels the bitmap turns off or leaves untouched.
+fn background() -> Framebuffer {
+    let mut framebuffer = Framebuffer::new();
+
+    for y in 0..Framebuffer::HEIGHT as isize {
+        for x in 0..Framebuffer::WIDTH as isize {
+            if (x + 2 * y) % 3 == 0 {
+                framebuffer.blit_pixel(x, y, Color::On);
+            }
+        }
+    }
+
+    framebuffer
+}
+
+fn render(bitmap: Bitmap) -> Framebuffer {
+    let mut framebuffer = background();
+    bitmap.draw(&mut framebuffer);
+    framebuffer
+}
+
+#[test]
+fn compressed_bitmaps_match_the_raw_ones() {
+    // including positions where the bitmaps get clipped by the screen
+    let positions = [
+        Vec2::new(0, 0),
+        Vec2::new(17, 5),
+        Vec2::new(-7, -3),
+        Vec2::new(78, 41),
+    ];
+
+    for (name, compressed, raw) in bitmaps() {
+        for (transformation, transform) in transformations() {
+            for position in positions {
+                assert!(
+                    render(transform(compressed(position)))
+                        == render(transform(Bitmap::new(raw, position))),
+                    "the {name} bitmap {transformation} at ({}, {}) differs from its image",
+                    position.x,
+                    position.y,
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn compressed_bitmaps_match_the_raw_ones_as_masks() {
+    for (name, compressed, raw) in bitmaps() {
+        let position = Vec2::new(9, 4);
+
+        assert!(
+            render(compressed(position).masked(raw).rotate_90())
+                == render(Bitmap::new(raw, position).masked(raw).rotate_90()),
+            "the {name} bitmap masked by its image differs from its image",
+        );
+    }
+}
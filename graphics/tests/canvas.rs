@@ -0,0 +1,154 @@
+//! Checks the fast paths of [`Framebuffer`] against the per-pixel defaults of
+//! [`Canvas`]. Both the framebuffer and the buffered display driver build them
+//! on [`ChunkSpan`](hilton_graphics::canvas::ChunkSpan).
+
+use hilton_graphics::canvas::{Canvas, Color, Framebuffer, Vec2};
+use std::ops::RangeInclusive;
+
+/// A framebuffer which only blits single pixels, leaving everything else to
+/// the defaults of [`Canvas`].
+struct PerPixel(Framebuffer);
+
+impl Canvas for PerPixel {
+    fn blit_pixel(&mut self, x: isize, y: isize, color: Color) {
+        self.0.blit_pixel(x, y, color);
+    }
+}
+
+enum Blit {
+    Span(RangeInclusive<isize>, isize),
+    Chunk(isize, isize, u8),
+    Rect(Vec2<isize>, Vec2<isize>),
+}
+
+impl Blit {
+    fn apply(&self, canvas: &mut impl Canvas, color: Color) {
+        match *self {
+            Self::Span(ref xs, y) => canvas.blit_span(xs.clone(), y, color),
+            Self::Chunk(x, y, chunk) => canvas.blit_chunk(x, y, chunk, color),
+            Self::Rect(position, size) => canvas.fill_rect(position, size, color),
+        }
+    }
+
+    fn describe(&self) -> String {
+        match *self {
+            Self::Span(ref xs, y) => format!("span {xs:?} at y = {y}"),
+            Self::Chunk(x, y, chunk) => format!("chunk {chunk:#04x} at ({x}, {y})"),
+            Self::Rect(position, size) => format!(
+                "rectangle {}x{} at ({}, {})",
+                size.x, size.y, position.x, position.y
+            ),
+        }
+    }
+}
+
+/// Returns a framebuffer with a pattern irregular enough for blitting with
+/// [`Color::Off`] and [`Color::Toggle`] to have something to change.
+fn background() -> Framebuffer {
+    let mut framebuffer = Framebuffer::new();
+
+    for y in 0..Framebuffer::HEIGHT as isize {
+        for x in 0..Framebuffer::WIDTH as isize {
+            if (x * 7 + y * 13 + x * y) % 5 < 2 {
+                framebuffer.blit_pixel(x, y, Color::On);
+            }
+        }
+    }
+
+    framebuffer
+}
+
+/// Checks that the fast path and the per-pixel default blit the same pixels
+/// onto the same background, in every color.
+fn assert_same(blits: impl IntoIterator<Item = Blit>) {
+    for blit in blits {
+        for color in [Color::On, Color::Off, Color::Toggle] {
+            let mut fast = background();
+            let mut slow = PerPixel(background());
+
+            blit.apply(&mut fast, color);
+            blit.apply(&mut slow, color);
+
+            assert!(
+                fast == slow.0,
+                "{} differs in {}",
+                blit.describe(),
+                match color {
+                    Color::On => "Color::On",
+                    Color::Off => "Color::Off",
+                    Color::Toggle => "Color::Toggle",
+                },
+            );
+        }
+    }
+}
+
+const WIDTH: isize = Framebuffer::WIDTH as isize;
+const HEIGHT: isize = Framebuffer::HEIGHT as isize;
+
+#[test]
+fn blit_span() {
+    // spans with the end before the start are empty
+    let xs = [
+        (-10, -1),
+        (-10, 0),
+        (-3, 5),
+        (0, WIDTH - 1),
+        (7, 7),
+        (7, 6),
+        (10, 2),
+        (WIDTH - 3, WIDTH + 3),
+        (WIDTH, WIDTH + 10),
+        (-100, 100),
+    ];
+    let ys = [-9, -1, 0, 5, 7, 8, 31, HEIGHT - 1, HEIGHT, HEIGHT + 7];
+
+    assert_same(
+        xs.iter()
+            .flat_map(|&(start, end)| ys.map(|y| Blit::Span(start..=end, y))),
+    );
+}
+
+#[test]
+fn blit_chunk() {
+    let xs = [-1, 0, 13, WIDTH - 1, WIDTH];
+    let ys = (-10..=HEIGHT + 2).collect::<Vec<_>>();
+    let chunks = [0x00, 0x01, 0x80, 0xa5, 0xff];
+
+    assert_same(xs.into_iter().flat_map(|x| {
+        ys.iter()
+            .flat_map(move |&y| chunks.map(|chunk| Blit::Chunk(x, y, chunk)))
+    }));
+}
+
+#[test]
+fn fill_rect() {
+    let positions = [
+        Vec2::new(-5, -5),
+        Vec2::new(0, 0),
+        Vec2::new(3, 7),
+        Vec2::new(10, 8),
+        Vec2::new(20, 13),
+        Vec2::new(WIDTH - 2, HEIGHT - 3),
+        Vec2::new(WIDTH, HEIGHT),
+    ];
+    let sizes = [
+        Vec2::new(0, 0),
+        Vec2::new(0, 5),
+        Vec2::new(5, 0),
+        Vec2::new(-3, 4),
+        Vec2::new(4, -3),
+        Vec2::new(1, 1),
+        Vec2::new(6, 1),
+        Vec2::new(6, 8),
+        Vec2::new(9, 17),
+        Vec2::new(2, 40),
+        Vec2::new(WIDTH + 10, HEIGHT + 10),
+    ];
+
+    assert_same(
+        positions
+            .iter()
+            .flat_map(|&position| sizes.map(|size| Blit::Rect(position, size))),
+    );
+}
@@ -0,0 +1,42 @@
+//! Checks ellipses too large for their equation to fit in 32 bits, whose
+//! edges still cross the screen.
+
+use hilton_graphics::canvas::{Circle, Color, Draw, Ellipse, Framebuffer, ReadableCanvas, Vec2};
+
+fn render(shape: impl Draw) -> Framebuffer {
+    let mut framebuffer = Framebuffer::new();
+    shape.draw(&mut framebuffer);
+    framebuffer
+}
+
+#[test]
+fn large_ellipses_match_circles() {
+    for radius in [150, 400, 2000] {
+        for center in [Vec2::new(42, radius + 10), Vec2::new(radius - 30, 24)] {
+            let ellipse = render(Ellipse::new(center, Vec2::new(radius, radius), Color::On));
+            let circle = render(Circle::new(center, radius, Color::On));
+
+            assert!(
+                ellipse == circle,
+                "radius {radius} around ({}, {})",
+                center.x,
+                center.y,
+            );
+        }
+    }
+}
+
+#[test]
+fn large_ellipses_cover_the_screen() {
+    let framebuffer = render(Ellipse::new(
+        Vec2::new(42, 24),
+        Vec2::new(600, 300),
+        Color::On,
+    ));
+
+    for y in 0..Framebuffer::HEIGHT as isize {
+        for x in 0..Framebuffer::WIDTH as isize {
+            assert!(framebuffer.get_pixel(x, y) == Some(Color::On), "({x}, {y})");
+        }
+    }
+}
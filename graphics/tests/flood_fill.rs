@@ -0,0 +1,147 @@
+use hilton_graphics::{
+    canvas::{Canvas, Circle, Color, Draw, FloodFill, Framebuffer, ReadableCanvas, Style, Vec2},
+    hilton,
+};
+
+/// Returns a framebuffer with a comb of one pixel wide teeth hanging from its
+/// top row, separated by one pixel wide walls.
+fn comb() -> Framebuffer {
+    let mut framebuffer = Framebuffer::new();
+
+    for x in (1..Framebuffer::WIDTH as isize).step_by(2) {
+        framebuffer.fill_rect(
+            Vec2::new(x, 1),
+            Vec2::new(1, Framebuffer::HEIGHT as isize - 1),
+            Color::On,
+        );
+    }
+
+    framebuffer
+}
+
+#[test]
+fn fills_past_stack_overflow() {
+    // filling the top row pushes a seed for each of the 42 teeth at once
+    let mut framebuffer = comb();
+    FloodFill::new(Vec2::new(0, 0), Color::On).draw(&mut framebuffer);
+
+    let mut expected = Framebuffer::new();
+    expected.fill_rect(
+        Vec2::new(0, 0),
+        Vec2::new(Framebuffer::WIDTH as isize, Framebuffer::HEIGHT as isize),
+        Color::On,
+    );
+
+    assert!(framebuffer == expected);
+}
+
+/// Fills the region the straightforward way, with a queue as large as needed.
+fn reference_fill(framebuffer: &mut Framebuffer, seed: Vec2<isize>, color: Color) {
+    let target = match framebuffer.get_pixel(seed.x, seed.y) {
+        Some(target) => target,
+        None => return,
+    };
+    let color = match (color, target) {
+        (Color::Toggle, Color::On) => Color::Off,
+        (Color::Toggle, _) => Color::On,
+        (color, _) => color,
+    };
+
+    if color == target {
+        return;
+    }
+
+    framebuffer.blit_pixel(seed.x, seed.y, color);
+    let mut queue = vec![seed];
+
+    while let Some(Vec2 { x, y }) = queue.pop() {
+        for (x, y) in [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)] {
+            if framebuffer.get_pixel(x, y) == Some(target) {
+                framebuffer.blit_pixel(x, y, color);
+                queue.push(Vec2::new(x, y));
+            }
+        }
+    }
+}
+
+#[track_caller]
+fn assert_fills_like_reference(framebuffer: &Framebuffer, seed: Vec2<isize>, color: Color) {
+    let mut actual = framebuffer.clone();
+    FloodFill::new(seed, color).draw(&mut actual);
+
+    let mut expected = framebuffer.clone();
+    reference_fill(&mut expected, seed, color);
+
+    assert!(actual == expected, "fill from ({}, {})", seed.x, seed.y);
+}
+
+#[test]
+fn fills_inside_outline() {
+    let mut framebuffer = Framebuffer::new();
+    let outline = Style::Outlined {
+        paint: Color::On.into(),
+        width: 1,
+    };
+    Circle::new(Vec2::new(40, 24), 15, outline).draw(&mut framebuffer);
+    Circle::new(Vec2::new(40, 24), 5, outline).draw(&mut framebuffer);
+
+    for seed in [Vec2::new(40, 24), Vec2::new(40, 14), Vec2::new(1, 1)] {
+        for color in [Color::On, Color::Toggle] {
+            assert_fills_like_reference(&framebuffer, seed, color);
+        }
+    }
+}
+
+#[test]
+fn stays_inside_outline_past_stack_overflow() {
+    // the teeth hanging inside the outline leave more than 24 spans waiting
+    // to be filled at once
+    let mut framebuffer = Framebuffer::new();
+    let outline = Style::Outlined {
+        paint: Color::On.into(),
+        width: 1,
+    };
+    Circle::new(Vec2::new(42, 24), 22, outline).draw(&mut framebuffer);
+
+    for x in (22..=60).step_by(2) {
+        framebuffer.fill_rect(Vec2::new(x, 12), Vec2::new(1, 20), Color::On);
+    }
+
+    let mut filled = framebuffer.clone();
+    FloodFill::new(Vec2::new(42, 8), Color::On).draw(&mut filled);
+
+    // the corners lie outside of the outline
+    for (x, y) in [(0, 0), (83, 0), (0, 47), (83, 47)] {
+        assert!(filled.get_pixel(x, y) == Some(Color::Off), "({x}, {y})");
+    }
+
+    for color in [Color::On, Color::Toggle] {
+        assert_fills_like_reference(&framebuffer, Vec2::new(42, 8), color);
+    }
+}
+
+#[test]
+fn fills_hilton() {
+    let mut framebuffer = Framebuffer::new();
+    hilton::draw(&mut framebuffer);
+
+    for y in (0..Framebuffer::HEIGHT as isize).step_by(3) {
+        for x in (0..Framebuffer::WIDTH as isize).step_by(3) {
+            assert_fills_like_reference(&framebuffer, Vec2::new(x, y), Color::Toggle);
+        }
+    }
+}
+
+#[test]
+fn ignores_seeds_off_canvas_or_of_fill_color() {
+    let mut framebuffer = comb();
+
+    for seed in [Vec2::new(-1, 0), Vec2::new(0, Framebuffer::HEIGHT as isize)] {
+        FloodFill::new(seed, Color::On).draw(&mut framebuffer);
+    }
+
+    FloodFill::new(Vec2::new(1, 5), Color::On).draw(&mut framebuffer);
+    FloodFill::new(Vec2::new(0, 5), Color::Off).draw(&mut framebuffer);
+
+    assert!(framebuffer == comb());
+}
@@ -0,0 +1,79 @@
+use hilton_graphics::canvas::{Bounds, Vec2};
+
+fn bounds(x: isize, y: isize, width: isize, height: isize) -> Bounds {
+    Bounds::new(Vec2::new(x, y), Vec2::new(width, height))
+}
+
+#[test]
+fn contains() {
+    let bounds = bounds(-2, 3, 4, 2);
+
+    assert!(bounds.contains(Vec2::new(-2, 3)));
+    assert!(bounds.contains(Vec2::new(1, 4)));
+    assert!(!bounds.contains(Vec2::new(2, 4)));
+    assert!(!bounds.contains(Vec2::new(1, 5)));
+    assert!(!bounds.contains(Vec2::new(-3, 3)));
+    assert!(!bounds.contains(Vec2::new(-2, 2)));
+}
+
+#[test]
+fn empty_bounds_contain_nothing() {
+    for empty in [Bounds::EMPTY, bounds(0, 0, 0, 5), bounds(0, 0, 5, -1)] {
+        assert!(empty.is_empty());
+        assert!(!empty.contains(Vec2::new(0, 0)));
+        assert!(empty == Bounds::EMPTY);
+    }
+}
+
+#[test]
+fn intersect() {
+    assert!(bounds(0, 0, 4, 4).intersect(bounds(2, 1, 4, 4)) == bounds(2, 1, 2, 3));
+    assert!(bounds(0, 0, 4, 4).intersect(bounds(1, 1, 2, 2)) == bounds(1, 1, 2, 2));
+    assert!(bounds(-5, -5, 3, 3).intersect(bounds(-4, -6, 1, 10)) == bounds(-4, -5, 1, 3));
+}
+
+#[test]
+fn intersect_disjoint_or_touching() {
+    assert!(bounds(0, 0, 4, 4).intersect(bounds(4, 0, 4, 4)).is_empty());
+    assert!(bounds(0, 0, 4, 4).intersect(bounds(0, 4, 4, 4)).is_empty());
+    assert!(bounds(0, 0, 4, 4)
+        .intersect(bounds(10, 10, 1, 1))
+        .is_empty());
+    assert!(bounds(0, 0, 4, 4).intersect(Bounds::EMPTY).is_empty());
+}
+
+#[test]
+fn union() {
+    assert!(bounds(0, 0, 2, 2).union(bounds(3, -1, 1, 1)) == bounds(0, -1, 4, 3));
+    assert!(bounds(0, 0, 4, 4).union(bounds(1, 1, 2, 2)) == bounds(0, 0, 4, 4));
+}
+
+#[test]
+fn union_ignores_empty_bounds() {
+    // empty bounds far away mustn't stretch the result towards them
+    assert!(bounds(5, 5, 2, 2).union(bounds(-100, -100, 0, 0)) == bounds(5, 5, 2, 2));
+    assert!(bounds(-100, -100, 3, -3).union(bounds(5, 5, 2, 2)) == bounds(5, 5, 2, 2));
+    assert!(Bounds::EMPTY.union(Bounds::EMPTY).is_empty());
+}
+
+#[test]
+fn union_contains_both() {
+    let a = bounds(-3, 2, 5, 4);
+    let b = bounds(4, -1, 2, 2);
+    let union = a.union(b);
+
+    for y in -5..10 {
+        for x in -5..10 {
+            let point = Vec2::new(x, y);
+
+            if a.contains(point) || b.contains(point) {
+                assert!(union.contains(point));
+            }
+
+            assert_eq!(
+                a.intersect(b).contains(point),
+                a.contains(point) && b.contains(point),
+            );
+        }
+    }
+}
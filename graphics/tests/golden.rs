@@ -0,0 +1,93 @@
+//! Renders scenes onto a [`Framebuffer`] and compares them against the PNGs in
+//! `tests/golden`. Run with the `UPDATE_GOLDEN` environment variable set to
+//! overwrite the PNGs with the current renders instead.
+
+use hilton_graphics::{
+    canvas::{Color, Draw, Framebuffer, ReadableCanvas, Vec2},
+    hilton,
+};
+use image::{GrayImage, Luma};
+use std::{env, fmt::Write as _, path::PathBuf};
+
+fn assert_golden(name: &str, framebuffer: &Framebuffer) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/golden")
+        .join(name)
+        .with_extension("png");
+    let actual = GrayImage::from_fn(
+        Framebuffer::WIDTH as u32,
+        Framebuffer::HEIGHT as u32,
+        |x, y| match framebuffer.get_pixel(x as isize, y as isize) {
+            Some(Color::On) => Luma([0]),
+            _ => Luma([255]),
+        },
+    );
+
+    if env::var_os("UPDATE_GOLDEN").is_some() {
+        actual.save(&path).unwrap();
+        return;
+    }
+
+    let expected = image::open(&path)
+        .unwrap_or_else(|error| panic!("failed to open {}: {error}", path.display()))
+        .to_luma8();
+
+    assert_eq!(
+        expected.dimensions(),
+        actual.dimensions(),
+        "{} has the wrong size",
+        path.display(),
+    );
+
+    // '#' and '.' are pixels on and off in both images, 'X' and 'O' are
+    // pixels which should have been on and off respectively
+    let mut diff = String::new();
+    let mut mismatches = 0;
+
+    for (y, (expected, actual)) in expected.rows().zip(actual.rows()).enumerate() {
+        for (expected, actual) in expected.zip(actual) {
+            let symbol = match (expected.0[0] < 128, actual.0[0] < 128) {
+                (true, true) => '#',
+                (false, false) => '.',
+                (true, false) => 'X',
+                (false, true) => 'O',
+            };
+
+            mismatches += matches!(symbol, 'X' | 'O') as usize;
+            diff.push(symbol);
+        }
+
+        writeln!(diff, " {y}").unwrap();
+    }
+
+    assert!(
+        mismatches == 0,
+        "{mismatches} pixels differ from {}:\n{diff}",
+        path.display(),
+    );
+}
+
+#[test]
+fn hilton() {
+    let mut framebuffer = Framebuffer::new();
+    hilton::draw(&mut framebuffer);
+
+    assert_golden("hilton", &framebuffer);
+}
+
+#[test]
+fn hilton_posed() {
+    let mut scene = hilton::scene();
+    scene.set_anchor(scene.anchor() + Vec2::new(-20, 4));
+    scene.layer_mut(hilton::STRAND).set_visible(false);
+    scene.layer_mut(hilton::TAIL).move_by(Vec2::new(2, -1));
+
+    if let hilton::Part::Eyes { look_direction } = scene.layer_mut(hilton::EYES).drawable_mut() {
+        *look_direction = Vec2::new(1, 1);
+    }
+
+    let mut framebuffer = Framebuffer::new();
+    scene.draw(&mut framebuffer);
+
+    assert_golden("hilton_posed", &framebuffer);
+}
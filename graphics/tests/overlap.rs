@@ -0,0 +1,82 @@
+//! Checks the overlap queries of [`Bitmap`] against what actually gets drawn.
+
+use hilton_graphics::canvas::{Bitmap, Canvas, Color, Draw, Framebuffer, ReadableCanvas, Vec2};
+use stockbook::{stamp, Stamp};
+
+static TORSO: Stamp = stamp!("assets/torso.png");
+
+const VARIANTS: usize = 10;
+
+/// Returns one of a few bitmaps, stored in different ways and transformed in
+/// different ways, depending on `variant`.
+fn bitmap(variant: usize, position: Vec2<isize>) -> Bitmap {
+    match variant {
+        0 => Bitmap::ear(position),
+        1 => Bitmap::ear(position).flip_h(),
+        2 => Bitmap::ear(position).rotate_90().flip_v(),
+        3 => Bitmap::ear(position).rotate_270().scale(2),
+        4 => Bitmap::ear(position).opaque().color(Color::Off),
+        // the only bitmap stored as runs
+        5 => Bitmap::torso(position),
+        6 => Bitmap::torso(position).rotate_90(),
+        7 => Bitmap::torso(position).flip_v().scale(2),
+        8 => Bitmap::torso(position).rotate_180().flip_h(),
+        _ => Bitmap::new(&TORSO, position).rotate_270().masked(&TORSO),
+    }
+}
+
+/// Returns a framebuffer with the black pixels of the bitmap drawn onto it.
+fn render(bitmap: Bitmap) -> Framebuffer {
+    let mut framebuffer = Framebuffer::new();
+    bitmap.color(Color::On).draw(&mut framebuffer);
+    framebuffer
+}
+
+fn renders_overlap(a: &Framebuffer, b: &Framebuffer) -> bool {
+    (0..Framebuffer::HEIGHT as isize).any(|y| {
+        (0..Framebuffer::WIDTH as isize)
+            .any(|x| a.get_pixel(x, y) == Some(Color::On) && b.get_pixel(x, y) == Some(Color::On))
+    })
+}
+
+#[test]
+fn overlaps() {
+    for variant in 0..VARIANTS {
+        let a = || bitmap(variant, Vec2::new(30, 20));
+        let rendered = render(a());
+
+        for other in 0..VARIANTS {
+            for dy in (-18..=18).step_by(3) {
+                for dx in (-18..=18).step_by(3) {
+                    let b = || bitmap(other, Vec2::new(30 + dx, 20 + dy));
+
+                    assert_eq!(
+                        a().overlaps(&b()),
+                        renders_overlap(&rendered, &render(b())),
+                        "variants {variant} and {other} offset by ({dx}, {dy})",
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn overlaps_canvas() {
+    let mut canvas = Framebuffer::new();
+    canvas.fill_rect(Vec2::new(40, 20), Vec2::new(3, 5), Color::On);
+
+    for variant in 0..VARIANTS {
+        for y in (0..=30).step_by(2) {
+            for x in (10..=50).step_by(2) {
+                let bitmap = || bitmap(variant, Vec2::new(x, y));
+
+                assert_eq!(
+                    bitmap().overlaps_canvas(&canvas),
+                    renders_overlap(&render(bitmap()), &canvas),
+                    "variant {variant} at ({x}, {y})",
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//! Checks the levels of the dithering patterns and how patterns get tiled and
+//! painted.
+
+use hilton_graphics::canvas::{Color, Pattern};
+
+/// Returns the number of pixels set in a single 8x8 tile of the pattern.
+fn set_pixels(pattern: Pattern) -> usize {
+    (0..8)
+        .flat_map(|y| (0..8).map(move |x| (x, y)))
+        .filter(|&(x, y)| pattern.color_at(x, y) == Some(Color::On))
+        .count()
+}
+
+/// Checks that every pixel set at a level stays set at all the levels above
+/// it, so that neighboring shades blend into each other.
+#[track_caller]
+fn assert_nested(patterns: &[Pattern]) {
+    for (level, pair) in patterns.windows(2).enumerate() {
+        for y in 0..8 {
+            for x in 0..8 {
+                assert!(
+                    pair[0].color_at(x, y) != Some(Color::On)
+                        || pair[1].color_at(x, y) == Some(Color::On),
+                    "({x}, {y}) is set at level {level} but not at level {}",
+                    level + 1,
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn bayer_2x2_levels() {
+    let patterns = (0..=4).map(Pattern::bayer_2x2).collect::<Vec<_>>();
+
+    for (level, &pattern) in patterns.iter().enumerate() {
+        assert_eq!(set_pixels(pattern), level * 16, "level {level}");
+    }
+
+    assert_nested(&patterns);
+    assert!(Pattern::bayer_2x2(5) == Pattern::bayer_2x2(4));
+    assert!(Pattern::bayer_2x2(u8::MAX) == Pattern::bayer_2x2(4));
+}
+
+#[test]
+fn bayer_4x4_levels() {
+    let patterns = (0..=16).map(Pattern::bayer_4x4).collect::<Vec<_>>();
+
+    for (level, &pattern) in patterns.iter().enumerate() {
+        assert_eq!(set_pixels(pattern), level * 4, "level {level}");
+    }
+
+    assert_nested(&patterns);
+    assert!(Pattern::bayer_4x4(17) == Pattern::bayer_4x4(16));
+    assert!(Pattern::bayer_4x4(u8::MAX) == Pattern::bayer_4x4(16));
+
+    // every other level of the 4x4 matrix is a level of the 2x2 one
+    for level in 0..=4 {
+        assert!(Pattern::bayer_4x4(level * 4) == Pattern::bayer_2x2(level));
+    }
+}
+
+#[test]
+fn checkerboard() {
+    let checkerboard = Pattern::checkerboard();
+
+    assert!(checkerboard == Pattern::bayer_2x2(2));
+
+    for y in 0..8 {
+        for x in 0..8 {
+            let expected = match (x + y) % 2 {
+                0 => Color::On,
+                _ => Color::Off,
+            };
+
+            assert!(checkerboard.color_at(x, y) == Some(expected), "({x}, {y})");
+        }
+    }
+}
+
+#[test]
+fn patterns_tile_the_canvas() {
+    let pattern = Pattern::new([0x81, 0x42, 0x24, 0x18, 0x0f, 0xf0, 0x33, 0xcc]);
+
+    for y in -16..16 {
+        for x in -16..16 {
+            let tile = pattern.color_at(x & 7, y & 7);
+
+            assert!(pattern.color_at(x, y) == tile, "({x}, {y})");
+            assert!(pattern.color_at(x + 8, y - 24) == tile, "({x}, {y})");
+        }
+    }
+
+    // the most significant bit of every row is its leftmost pixel
+    assert!(pattern.color_at(0, 0) == Some(Color::On));
+    assert!(pattern.color_at(1, 0) == Some(Color::Off));
+    assert!(pattern.color_at(7, 0) == Some(Color::On));
+    assert!(pattern.color_at(4, 4) == Some(Color::On));
+    assert!(pattern.color_at(3, 4) == Some(Color::Off));
+}
+
+#[test]
+fn colors_and_transparency() {
+    let pattern = Pattern::checkerboard();
+    let toggle = pattern.color(Color::Toggle);
+    let transparent = pattern.transparent();
+    let both = pattern.color(Color::Off).transparent();
+
+    for y in 0..8 {
+        for x in 0..8 {
+            let (toggle_color, transparent_color, both_color) =
+                match pattern.color_at(x, y) == Some(Color::On) {
+                    true => (Color::Toggle, Some(Color::On), Some(Color::Off)),
+                    false => (Color::Off, None, None),
+                };
+
+            assert!(toggle.color_at(x, y) == Some(toggle_color), "({x}, {y})");
+            assert!(
+                transparent.color_at(x, y) == transparent_color,
+                "({x}, {y})"
+            );
+            assert!(both.color_at(x, y) == both_color, "({x}, {y})");
+        }
+    }
+}
@@ -0,0 +1,371 @@
+//! Checks the shape primitives against each other and against the properties
+//! their documentation promises.
+
+use hilton_graphics::canvas::{
+    Arc, Canvas, Circle, Color, Draw, Ellipse, Framebuffer, Line, Paint, Polygon, ReadableCanvas,
+    Rect, RoundedRect, Style, Triangle, Vec2,
+};
+
+fn render(drawable: &impl Draw) -> Framebuffer {
+    let mut framebuffer = Framebuffer::new();
+    drawable.draw(&mut framebuffer);
+    framebuffer
+}
+
+/// Returns the coordinates of every pixel which is on.
+fn pixels(framebuffer: &Framebuffer) -> impl Iterator<Item = (isize, isize)> + '_ {
+    (0..Framebuffer::HEIGHT as isize).flat_map(move |y| {
+        (0..Framebuffer::WIDTH as isize)
+            .filter(move |&x| framebuffer.get_pixel(x, y) == Some(Color::On))
+            .map(move |x| (x, y))
+    })
+}
+
+/// Returns the pixels which are on in exactly one of the framebuffers.
+fn difference(a: &Framebuffer, b: &Framebuffer) -> Framebuffer {
+    let mut difference = a.clone();
+
+    for (x, y) in pixels(b) {
+        difference.blit_pixel(x, y, Color::Toggle);
+    }
+
+    difference
+}
+
+/// Returns the pixels which are on in either of the framebuffers.
+fn union(a: &Framebuffer, b: &Framebuffer) -> Framebuffer {
+    let mut union = a.clone();
+
+    for (x, y) in pixels(b) {
+        union.blit_pixel(x, y, Color::On);
+    }
+
+    union
+}
+
+/// Checks that toggling draws the same pixels as turning them on, which only
+/// holds if no pixel gets blitted twice, and that the bounds of the drawable
+/// contain all of them.
+#[track_caller]
+fn assert_well_behaved(on: impl Draw, toggle: impl Draw, description: &str) {
+    let framebuffer = render(&on);
+
+    assert!(
+        framebuffer == render(&toggle),
+        "{description} blits some pixels more than once",
+    );
+
+    for (x, y) in pixels(&framebuffer) {
+        assert!(
+            on.bounds().contains(Vec2::new(x, y)),
+            "{description} blits ({x}, {y}) outside of its bounds",
+        );
+    }
+}
+
+/// Returns the styles the shapes get checked with, in a given color.
+fn styles(color: Color) -> [Style; 4] {
+    let paint = Paint::from(color);
+
+    [
+        Style::Filled(paint),
+        Style::Outlined { paint, width: 1 },
+        Style::Outlined { paint, width: 3 },
+        Style::FilledOutlined {
+            fill: paint,
+            outline: paint,
+            width: 2,
+        },
+    ]
+}
+
+#[test]
+fn lines() {
+    let ends = [
+        Vec2::new(30, 20),
+        Vec2::new(2, 3),
+        Vec2::new(70, 9),
+        Vec2::new(35, 41),
+        Vec2::new(30, 20),
+        Vec2::new(81, 20),
+        Vec2::new(30, 4),
+    ];
+
+    for &start in &ends {
+        for &end in &ends {
+            for thickness in [1, 2, 3, 6] {
+                let line = |color| Line::new(start, end, color).thickness(thickness);
+                let description = format!(
+                    "line from ({}, {}) to ({}, {}) {thickness} thick",
+                    start.x, start.y, end.x, end.y
+                );
+
+                assert_well_behaved(line(Color::On), line(Color::Toggle), &description);
+
+                // every step along the major axis is `thickness` pixels long
+                let framebuffer = render(&line(Color::On));
+                let is_horizontal = (end.x - start.x).abs() >= (end.y - start.y).abs();
+                // the coordinates along the major axis and across it
+                let axes = |(x, y)| match is_horizontal {
+                    true => (x, y),
+                    false => (y, x),
+                };
+                let major = (axes((start.x, start.y)).0, axes((end.x, end.y)).0);
+
+                for a in major.0.min(major.1)..=major.0.max(major.1) {
+                    let mut step = pixels(&framebuffer)
+                        .map(axes)
+                        .filter(|&(along, _)| along == a)
+                        .map(|(_, across)| across)
+                        .collect::<Vec<_>>();
+                    step.sort();
+
+                    assert_eq!(step.len(), thickness as usize, "{description} at {a}");
+                    assert_eq!(
+                        step[step.len() - 1] - step[0],
+                        thickness - 1,
+                        "{description} at {a}",
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn lines_include_both_ends() {
+    let framebuffer = render(&Line::new(Vec2::new(5, 40), Vec2::new(60, 7), Color::On));
+
+    assert!(framebuffer.get_pixel(5, 40) == Some(Color::On));
+    assert!(framebuffer.get_pixel(60, 7) == Some(Color::On));
+    assert!(
+        render(&Line::new(Vec2::new(5, 4), Vec2::new(9, 4), Color::On).thickness(0))
+            == Framebuffer::new()
+    );
+}
+
+#[test]
+fn triangles_are_polygons() {
+    let triangles = [
+        [Vec2::new(10, 5), Vec2::new(70, 20), Vec2::new(25, 44)],
+        [Vec2::new(42, 2), Vec2::new(42, 40), Vec2::new(5, 40)],
+        [Vec2::new(0, 0), Vec2::new(83, 47), Vec2::new(40, 24)],
+    ];
+
+    for [a, b, c] in triangles {
+        for style in styles(Color::On) {
+            assert!(
+                render(&Triangle::new(a, b, c, style)) == render(&Polygon::new([a, b, c], style))
+            );
+        }
+    }
+}
+
+#[test]
+fn polygons() {
+    let star = [
+        Vec2::new(42, 2),
+        Vec2::new(50, 18),
+        Vec2::new(70, 18),
+        Vec2::new(54, 29),
+        Vec2::new(62, 45),
+        Vec2::new(42, 34),
+        Vec2::new(22, 45),
+        Vec2::new(30, 29),
+        Vec2::new(14, 18),
+        Vec2::new(34, 18),
+    ];
+
+    for (on, toggle) in styles(Color::On).into_iter().zip(styles(Color::Toggle)) {
+        assert_well_behaved(Polygon::new(star, on), Polygon::new(star, toggle), "star");
+    }
+
+    // the filled inside includes the rows and columns of the vertices
+    let square = [
+        Vec2::new(3, 4),
+        Vec2::new(20, 4),
+        Vec2::new(20, 15),
+        Vec2::new(3, 15),
+    ];
+
+    assert!(
+        render(&Polygon::new(square, Color::On))
+            == render(&Rect::new(Vec2::new(3, 4), Vec2::new(18, 12), Color::On))
+    );
+}
+
+#[test]
+fn polygon_outlines_are_lines() {
+    let vertices = [
+        Vec2::new(5, 5),
+        Vec2::new(60, 10),
+        Vec2::new(40, 40),
+        Vec2::new(12, 30),
+    ];
+    let outline = Style::Outlined {
+        paint: Color::On.into(),
+        width: 1,
+    };
+
+    let mut lines = Framebuffer::new();
+
+    for (i, &start) in vertices.iter().enumerate() {
+        let end = vertices[(i + 1) % vertices.len()];
+        Line::new(start, end, Color::On).draw(&mut lines);
+    }
+
+    assert!(render(&Polygon::new(vertices, outline)) == lines);
+}
+
+#[test]
+fn arcs() {
+    let center = Vec2::new(42, 24);
+
+    for style in styles(Color::On) {
+        let circle = render(&Circle::new(center, 20, style));
+        let arc = |start, end| render(&Arc::new(center, 20, start, end, style));
+
+        assert!(arc(30, 390) == circle);
+        assert!(arc(-45, 315) == circle);
+        assert!(arc(90, 90) == Framebuffer::new());
+
+        // quarters put together make up the whole circle
+        let quarters = [(0, 90), (90, 180), (180, 270), (270, 360)]
+            .map(|(start, end)| arc(start, end))
+            .iter()
+            .fold(Framebuffer::new(), |all, quarter| union(&all, quarter));
+
+        assert!(quarters == circle);
+
+        // the first quarter lies above and to the right of the center
+        for (x, y) in pixels(&arc(0, 90)) {
+            assert!(x >= center.x && y <= center.y, "({x}, {y})");
+        }
+
+        // a reflex arc covers everything but the rest of the circle
+        assert!(union(&arc(0, 270), &arc(270, 360)) == circle);
+    }
+}
+
+#[test]
+fn outlines_are_filled_shapes_without_their_insides() {
+    let center = Vec2::new(40, 22);
+    let position = Vec2::new(6, 4);
+    let size = Vec2::new(61, 37);
+
+    for width in [1, 2, 5] {
+        let outline = Style::Outlined {
+            paint: Color::On.into(),
+            width,
+        };
+        let inset = Vec2::new(width, width);
+
+        assert!(
+            render(&Circle::new(center, 17, outline))
+                == difference(
+                    &render(&Circle::new(center, 17, Color::On)),
+                    &render(&Circle::new(center, 17 - width, Color::On)),
+                ),
+            "circle {width} wide",
+        );
+        assert!(
+            render(&Ellipse::new(center, Vec2::new(30, 14), outline))
+                == difference(
+                    &render(&Ellipse::new(center, Vec2::new(30, 14), Color::On)),
+                    &render(&Ellipse::new(center, Vec2::new(30, 14) - inset, Color::On)),
+                ),
+            "ellipse {width} wide",
+        );
+        assert!(
+            render(&Rect::new(position, size, outline))
+                == difference(
+                    &render(&Rect::new(position, size, Color::On)),
+                    &render(&Rect::new(position + inset, size - inset * 2, Color::On)),
+                ),
+            "rectangle {width} wide",
+        );
+        assert!(
+            render(&RoundedRect::new(position, size, 9, outline))
+                == difference(
+                    &render(&RoundedRect::new(position, size, 9, Color::On)),
+                    &render(&RoundedRect::new(
+                        position + inset,
+                        size - inset * 2,
+                        9 - width,
+                        Color::On,
+                    )),
+                ),
+            "rounded rectangle {width} wide",
+        );
+    }
+}
+
+#[test]
+fn shapes_blit_every_pixel_once() {
+    let center = Vec2::new(40, 22);
+    let position = Vec2::new(6, 4);
+
+    for (on, toggle) in styles(Color::On).into_iter().zip(styles(Color::Toggle)) {
+        for radius in [0, 1, 4, 20] {
+            assert_well_behaved(
+                Circle::new(center, radius, on),
+                Circle::new(center, radius, toggle),
+                &format!("circle of radius {radius}"),
+            );
+            assert_well_behaved(
+                Arc::new(center, radius, 20, 250, on),
+                Arc::new(center, radius, 20, 250, toggle),
+                &format!("arc of radius {radius}"),
+            );
+        }
+
+        for radii in [Vec2::new(0, 5), Vec2::new(30, 14), Vec2::new(8, 21)] {
+            assert_well_behaved(
+                Ellipse::new(center, radii, on),
+                Ellipse::new(center, radii, toggle),
+                &format!("ellipse of radii {}x{}", radii.x, radii.y),
+            );
+        }
+
+        for size in [
+            Vec2::new(1, 1),
+            Vec2::new(61, 37),
+            Vec2::new(-20, 7),
+            Vec2::new(6, 30),
+        ] {
+            assert_well_behaved(
+                Rect::new(position, size, on),
+                Rect::new(position, size, toggle),
+                &format!("rectangle {}x{}", size.x, size.y),
+            );
+
+            for radius in [0, 3, 9, 100] {
+                assert_well_behaved(
+                    RoundedRect::new(position, size, radius, on),
+                    RoundedRect::new(position, size, radius, toggle),
+                    &format!("rectangle {}x{} rounded by {radius}", size.x, size.y),
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn rounded_rects() {
+    let position = Vec2::new(6, 4);
+    let size = Vec2::new(61, 37);
+
+    for style in styles(Color::On) {
+        // no rounding makes a regular rectangle
+        assert!(
+            render(&RoundedRect::new(position, size, 0, style))
+                == render(&Rect::new(position, size, style))
+        );
+
+        // the largest rounding of a square makes a circle
+        assert!(
+            render(&RoundedRect::new(position, Vec2::new(31, 31), 100, style))
+                == render(&Circle::new(position + Vec2::new(15, 15), 15, style))
+        );
+    }
+}
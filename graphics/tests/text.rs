@@ -0,0 +1,159 @@
+//! Checks how [`Text`] lays out the glyphs of its [`Font`].
+
+use hilton_graphics::canvas::{Canvas, Color, Draw, Font, Framebuffer, ReadableCanvas, Text, Vec2};
+
+fn render(drawable: &impl Draw) -> Framebuffer {
+    let mut framebuffer = Framebuffer::new();
+    drawable.draw(&mut framebuffer);
+    framebuffer
+}
+
+fn render_glyph(font: Font, c: char, position: Vec2<isize>) -> Framebuffer {
+    let mut framebuffer = Framebuffer::new();
+    font.draw_glyph(&mut framebuffer, c, position, Color::On);
+    framebuffer
+}
+
+/// Returns the coordinates of every pixel which is on.
+fn pixels(framebuffer: &Framebuffer) -> impl Iterator<Item = (isize, isize)> + '_ {
+    (0..Framebuffer::HEIGHT as isize).flat_map(move |y| {
+        (0..Framebuffer::WIDTH as isize)
+            .filter(move |&x| framebuffer.get_pixel(x, y) == Some(Color::On))
+            .map(move |x| (x, y))
+    })
+}
+
+fn fonts() -> [(&'static str, Font); 2] {
+    [("regular", Font::regular()), ("tiny", Font::tiny())]
+}
+
+#[test]
+fn metrics() {
+    let regular = Font::regular();
+    let tiny = Font::tiny();
+
+    assert_eq!((regular.advance(), regular.line_height()), (6, 8));
+    assert_eq!((tiny.advance(), tiny.line_height()), (4, 6));
+
+    assert_eq!(Framebuffer::WIDTH as isize / regular.advance(), 14);
+    assert_eq!(Framebuffer::WIDTH as isize / tiny.advance(), 21);
+}
+
+#[test]
+fn glyphs_stay_inside_their_cells() {
+    for (name, font) in fonts() {
+        let size = Vec2::<isize>::from(font.glyph_size());
+        let position = Vec2::new(10, 20);
+
+        for c in ' '..='~' {
+            let framebuffer = render_glyph(font, c, position);
+
+            assert!(
+                c == ' ' || pixels(&framebuffer).next().is_some(),
+                "the {name} glyph of {c:?} is empty",
+            );
+
+            for (x, y) in pixels(&framebuffer) {
+                assert!(
+                    (position.x..position.x + size.x).contains(&x)
+                        && (position.y..position.y + size.y).contains(&y),
+                    "the {name} glyph of {c:?} blits ({x}, {y}) outside of its cell",
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn glyphs_leave_the_background_untouched() {
+    for (name, font) in fonts() {
+        let position = Vec2::new(3, 2);
+        let glyph = render_glyph(font, 'W', position);
+
+        // turning the glyph off on a lit screen turns off only its own pixels
+        let mut framebuffer = Framebuffer::new();
+        framebuffer.fill_rect(
+            Vec2::new(0, 0),
+            Vec2::new(Framebuffer::WIDTH, Framebuffer::HEIGHT).into(),
+            Color::On,
+        );
+        font.draw_glyph(&mut framebuffer, 'W', position, Color::Off);
+
+        for y in 0..Framebuffer::HEIGHT as isize {
+            for x in 0..Framebuffer::WIDTH as isize {
+                assert!(
+                    framebuffer.get_pixel(x, y) != glyph.get_pixel(x, y),
+                    "the {name} glyph of 'W' touches ({x}, {y})",
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn characters_without_a_glyph_are_question_marks() {
+    for (name, font) in fonts() {
+        let question_mark = render_glyph(font, '?', Vec2::new(0, 0));
+
+        for c in ['\t', '\u{7f}', 'é', '→'] {
+            assert!(
+                render_glyph(font, c, Vec2::new(0, 0)) == question_mark,
+                "the {name} font draws {c:?} differently from '?'",
+            );
+        }
+    }
+}
+
+#[test]
+fn text_is_laid_out_glyph_by_glyph() {
+    for (name, font) in fonts() {
+        let position = Vec2::new(2, 3);
+        let text = "Hi!\n\nab c\n?";
+
+        let mut expected = Framebuffer::new();
+
+        for (row, line) in text.split('\n').enumerate() {
+            for (column, c) in line.chars().enumerate() {
+                let cursor = position
+                    + Vec2::new(
+                        column as isize * font.advance(),
+                        row as isize * font.line_height(),
+                    );
+
+                font.draw_glyph(&mut expected, c, cursor, Color::On);
+            }
+        }
+
+        let text = Text::new(text, position, font, Color::On);
+        let framebuffer = render(&text);
+
+        assert!(framebuffer == expected, "{name} text");
+
+        for (x, y) in pixels(&framebuffer) {
+            assert!(
+                text.bounds().contains(Vec2::new(x, y)),
+                "{name} text blits ({x}, {y}) outside of its bounds",
+            );
+        }
+    }
+}
+
+#[test]
+fn text_bounds() {
+    let font = Font::regular();
+    let position = Vec2::new(4, 5);
+    let bounds = |text| Text::new(text, position, font, Color::On).bounds();
+
+    assert!(bounds("").is_empty());
+    assert!(bounds("\n\n").is_empty());
+
+    // no spacing after the last glyph of a line
+    let single = bounds("abc");
+    assert!(single.position == position);
+    assert!(single.size == Vec2::new(17, 7));
+
+    // the longest line decides the width, and empty lines still take up space
+    let lines = bounds("a\nabcd\n\nab");
+    assert!(lines.position == position);
+    assert!(lines.size == Vec2::new(23, 31));
+}